@@ -1,6 +1,13 @@
 import { Component, OnInit, signal, computed } from '@angular/core';
 import { FormsModule } from '@angular/forms';
-import { MorphoService, DeriveInfo, RacineInfo, SchemeInfo } from './services/morpho.service';
+import {
+  MorphoService,
+  CriteresSchemes,
+  DeriveInfo,
+  ProprietesScheme,
+  RacineInfo,
+  SchemeInfo,
+} from './services/morpho.service';
 
 type ActiveTab = 'racines' | 'derives' | 'validation' | 'schemes' | 'arbre';
 
@@ -40,6 +47,11 @@ export class App implements OnInit {
   nouveauSchemeNom = signal('');
   nouveauSchemePattern = signal('');
   nouveauSchemeDesc = signal('');
+  // Identifiant facultatif (second schème de même nom : فاعل_III)
+  nouveauSchemeId = signal('');
+  // Filtres de la liste des schèmes ('' = pas de filtre)
+  filtreNature = signal('');
+  filtreForme = signal('');
 
   // Statistiques
   totalRacines = computed(() => this.racinesList().length);
@@ -293,7 +305,17 @@ export class App implements OnInit {
   // === SCHEMES ===
   rafraichirSchemes() {
     try {
-      this.schemesList.set(this.morpho.obtenirTousSchemes());
+      const nature = this.filtreNature();
+      // "q2" : forme quadrilitère QII
+      const quadrilitere = this.filtreForme().startsWith('q');
+      const forme = Number(this.filtreForme().replace('q', ''));
+      const criteres: CriteresSchemes = {
+        proprietes: {
+          ...(nature ? { nature: nature as ProprietesScheme['nature'] } : {}),
+          ...(forme ? { forme, quadrilitere } : {}),
+        },
+      };
+      this.schemesList.set(this.morpho.filtrerSchemes(criteres));
     } catch {
       this.schemesList.set([]);
     }
@@ -303,17 +325,15 @@ export class App implements OnInit {
     const nom = this.nouveauSchemeNom().trim();
     const pattern = this.nouveauSchemePattern().trim();
     const desc = this.nouveauSchemeDesc().trim();
+    const id = this.nouveauSchemeId().trim();
     if (!nom || !pattern) { this.erreur.set('أدخل اسم ونمط الوزن'); return; }
     try {
-      const ok = this.morpho.ajouterScheme(nom, pattern, desc);
-      if (ok) {
-        this.message.set(`✓ تم إضافة الوزن "${nom}"`);
-        this.nouveauSchemeNom.set('');
-        this.nouveauSchemePattern.set('');
-        this.nouveauSchemeDesc.set('');
-      } else {
-        this.message.set('لم تتم الإضافة (ربما الوزن موجود)');
-      }
+      const cle = this.morpho.ajouterScheme(nom, pattern, desc, id ? { id } : undefined);
+      this.message.set(`✓ تم إضافة الوزن "${cle}"`);
+      this.nouveauSchemeNom.set('');
+      this.nouveauSchemePattern.set('');
+      this.nouveauSchemeDesc.set('');
+      this.nouveauSchemeId.set('');
       this.erreur.set('');
       this.rafraichirSchemes();
     } catch (e: any) {
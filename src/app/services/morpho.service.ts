@@ -1,47 +1,317 @@
 import { Injectable } from '@angular/core';
 import init, {
+  MoteurMorphologique,
+  PolitiqueConflit,
   init_app,
   ajouter_racine,
   chercher_racine,
   supprimer_racine,
   charger_racines_depuis_texte,
   obtenir_toutes_racines,
+  racines_frequentes,
+  racines_entre,
+  racines_prefixe,
+  racines_motif,
+  derives_frequents,
+  classe_racine,
+  sans_diacritiques,
   generer_derive,
   generer_tous_derives,
+  generer_famille,
   generer_et_stocker_derive,
   generer_et_stocker_tous_derives,
+  conjuguer,
   valider_mot_derive,
+  valider_mot_complet,
+  analyser_mot_derive,
+  analyser_mot_segmente,
+  valider_mot_segmente,
+  segmenter_mot,
+  analyser_texte,
   ajouter_derive_a_racine,
   obtenir_derives_stockes,
   supprimer_derive,
   obtenir_tous_schemes,
+  filtrer_schemes,
   ajouter_scheme,
   supprimer_scheme,
   exporter_donnees,
-  importer_donnees
+  importer_donnees,
+  fusionner_donnees
 } from 'moteur_morphologique';
 
 // Types pour les résultats
+export type CasFlexion = 'nominatif' | 'oblique';
+
+// Traits fléchis d'un mot validé avec une désinence (كاتبون)
+export interface Flexion {
+  genre: 'masculin' | 'feminin';
+  nombre: 'singulier' | 'duel' | 'pluriel';
+  cas?: CasFlexion;
+}
+
 export interface DeriveInfo {
   mot: string;
   schema: string;
+  flexion?: Flexion;
+  occurrences: number; // dans les textes analysés
 }
 
 export interface RacineInfo {
   racine: string;
   nombre_derives: number;
+  frequence: number; // occurrences dans les textes analysés
   derives: DeriveInfo[];
 }
 
+// Une page de racines trouvées par intervalle, préfixe ou motif
+export interface PageRacines {
+  total: number; // toutes pages confondues
+  decalage: number;
+  limite: number;
+  racines: RacineInfo[];
+}
+
+// Un dérivé stocké et sa racine (derivesFrequents)
+export interface DeriveFrequent extends DeriveInfo {
+  racine: string;
+}
+
+// Catégories de schèmes (voir Categorie dans hashing.rs)
+export type CategorieScheme =
+  | 'verbe'
+  | 'participe'
+  | 'masdar'
+  | 'nom'
+  | 'feminin'
+  | 'nisba'
+  | 'nom_de_lieu'
+  | 'nom_d_instrument'
+  | 'pluriel_brise'
+  | 'autre';
+
+// Propriétés typées d'un schème (voir proprietes.rs) ; toutes facultatives
+export interface ProprietesScheme {
+  forme?: number; // 1 à 15 (I à XV), 1 à 4 (QI à QIV) si quadrilitere
+  quadrilitere?: boolean; // forme d'un verbe quadrilitère (تفعلل : QII)
+  nature?: 'nom' | 'adjectif' | 'verbe' | 'masdar' | 'participe';
+  voix?: 'actif' | 'passif';
+  aspect?: 'accompli' | 'inaccompli' | 'imperatif';
+  nombre?: 'singulier' | 'duel' | 'pluriel';
+  genre?: 'masculin' | 'feminin';
+}
+
 export interface SchemeInfo {
-  id: string;
+  cle: string;
+  nom: string;
+  pattern: string;
+  description: string;
+  categorie: CategorieScheme;
+  // Clés des schèmes de pluriel brisé d'un singulier
+  pluriels: string[];
+  proprietes: ProprietesScheme;
+}
+
+// Options d'un schème ajouté : un champ absent prend sa valeur par défaut
+export interface OptionsScheme {
+  // Clé unique dans la table, le nom par défaut (فاعل_III)
+  id?: string;
+  categorie?: CategorieScheme;
+  pluriels?: string[];
+  proprietes?: ProprietesScheme;
+}
+
+// Critères de filtrage : un champ absent accepte tout
+export interface CriteresSchemes {
+  proprietes?: ProprietesScheme;
+  categorie?: CategorieScheme;
+  radicaux?: number;
+  // Nom du schème, tous identifiants confondus ("فاعل" → فاعل et فاعل_III)
+  modele?: string;
+}
+
+// Un mot de la famille d'une racine, avec ses pluriels brisés s'il en a
+export interface MembreFamille {
+  mot: string;
+  schema: string;
+  categorie: CategorieScheme;
+  pluriels: DeriveInfo[];
+}
+
+export type RoleClitique = 'conjonction' | 'preposition' | 'futur' | 'article' | 'pronom';
+
+export interface Clitique {
+  // Forme écrite dans le mot (و، ال، ه)
+  forme: string;
+  role: RoleClitique;
+}
+
+// Découpe d'un mot de texte : و + ال + مكتوب
+export interface Segmentation {
+  proclitiques: Clitique[];
+  base: string;
+  enclitiques: Clitique[];
+}
+
+export interface AnalyseResult {
+  racine: string;
+  schema: string;
+  classe: string;
+  dans_arbre: boolean;
+  // Désinence retirée pour retrouver la forme du schème (كاتبون)
+  flexion?: Flexion;
+  // Verbe conjugué du schème de l'accompli (يكتبون)
+  conjugaison?: Conjugaison;
+  // Présente pour l'analyse segmentée
+  segmentation?: Segmentation;
+}
+
+// Compte rendu de fusionnerDonnees
+export interface DeriveFusion {
+  racine: string;
+  mot: string;
+  schema: string;
+}
+
+export interface SchemeExporte {
+  cle: string;
   nom: string;
+  pattern: string;
+  description: string;
+  categorie: CategorieScheme;
+  pluriels?: string[];
+  proprietes?: ProprietesScheme;
+}
+
+export interface RapportFusion {
+  racines_ajoutees: string[];
+  derives_ajoutes: DeriveFusion[];
+  derives_ignores: DeriveFusion[];
+  schemes_ajoutes: string[];
+  schemes_ignores: string[];
+  conflits: { cle: string; local: SchemeExporte; importe: SchemeExporte; garde_importe: boolean }[];
+}
+
+export { PolitiqueConflit };
+
+// Exception levée par le moteur (MorphoError côté Rust)
+// code : RACINE_INTROUVABLE, SCHEME_INTROUVABLE, PATTERN_INVALIDE, NON_INITIALISE, ...
+export interface MorphoErreur extends Error {
+  code: string;
+}
+
+export function estMorphoErreur(e: unknown): e is MorphoErreur {
+  return e instanceof Error && typeof (e as MorphoErreur).code === 'string';
+}
+
+export interface FormeConjuguee {
+  personne: string;
+  pronom: string;
+  mot: string;
+}
+
+export interface TempsConjugue {
+  temps: string;
+  nom: string;
+  formes: FormeConjuguee[];
+}
+
+export type Temps = 'accompli' | 'accompli_passif' | 'inaccompli' | 'inaccompli_passif' | 'imperatif';
+
+// Place d'un verbe conjugué dans le tableau de sa racine (يكتبون : inaccompli 3mp)
+export interface Conjugaison {
+  temps: Temps;
+  personne: string; // "3mp", "2fs"...
+  voyelles?: string; // باب de la forme I ("a-u")
+}
+
+export interface TableConjugaison {
+  racine: string;
+  forme: string;
   modele: string;
+  classe: string;
+  // باب de la forme I (ex. "a-u"), absent pour les formes augmentées
+  voyelles?: string;
+  temps: TempsConjugue[];
 }
 
 export interface ValidationResult {
   valide: boolean;
   schema: string;
+  // Forme vocalisée reconnue (la saisie peut être nue)
+  forme?: string;
+  // Identifiants de tous les schèmes reconnus, `schema` en premier
+  schemes: string[];
+}
+
+// Une lecture d'un mot validé : un schème qui le reproduit
+export interface LectureMot {
+  schema: string;
+  nom: string;
+  forme: string;
+  categorie: CategorieScheme;
+  proprietes: ProprietesScheme;
+  description: string;
+  // Diacritiques ajoutés à la saisie (0 : saisie complète)
+  ajouts: number;
+  // Désinence reconnue, absente pour la forme de base du schème
+  flexion?: Flexion;
+  // Verbe conjugué : `proprietes` sont alors celles du mot conjugué
+  conjugaison?: Conjugaison;
+}
+
+// Validation complète : toutes les lectures, la plus proche en premier
+export interface ValidationComplete {
+  mot: string;
+  valide: boolean;
+  ambigu: boolean;
+  lectures: LectureMot[];
+}
+
+// Une découpe en clitiques dont la base est validée
+export interface ValidationSegmentee {
+  segmentation: Segmentation;
+  validation: ValidationComplete;
+}
+
+// Analyse d'un texte complet (voir texte.rs)
+export type StatutJeton = 'reconnu' | 'hors_lexique' | 'inconnu';
+
+export interface CandidatJeton {
+  segmentation: Segmentation;
+  racine: string;
+  scheme: string;
+  flexion?: Flexion;
+  conjugaison?: Conjugaison;
+  dans_arbre: boolean;
+}
+
+export interface JetonTexte {
+  position: number; // indice du premier caractère dans le texte
+  texte: string;
+  forme: string; // forme normalisée
+  statut: StatutJeton;
+  candidats: CandidatJeton[];
+}
+
+export interface Frequence {
+  valeur: string;
+  occurrences: number;
+}
+
+export interface StatistiquesTexte {
+  jetons: number;
+  reconnus: number;
+  hors_lexique: number;
+  inconnus: number;
+  couverture: number; // pourcentage de jetons reconnus
+  racines: Frequence[];
+  schemes: Frequence[];
+}
+
+export interface AnalyseTexte {
+  jetons: JetonTexte[];
+  statistiques: StatistiquesTexte;
 }
 
 @Injectable({
@@ -68,31 +338,43 @@ export class MorphoService {
     }
   }
 
-  // === Utilitaire: extraire les 3 caractères ===
-  private extraireCaracteres(racine: string): [string, string, string] {
-    const chars = [...racine];
-    if (chars.length !== 3) {
-      throw new Error('La racine doit contenir exactement 3 caractères arabes');
+  // === Lexique indépendant (brouillon, version publiée, ...) ===
+  // Les méthodes de ce service travaillent sur le lexique par défaut ;
+  // un moteur créé ici a son propre arbre et ses propres schèmes.
+  // Penser à appeler free() quand il n'est plus utilisé.
+  creerLexique(): MoteurMorphologique {
+    return new MoteurMorphologique();
+  }
+
+  // === Utilitaire: extraire les 3 ou 4 caractères (c4 absent pour une racine trilitère) ===
+  private extraireCaracteres(racine: string): [string, string, string, string | undefined] {
+    const chars = [...racine].filter(c => c.trim() !== '');
+    if (chars.length !== 3 && chars.length !== 4) {
+      throw new Error('La racine doit contenir 3 ou 4 caractères arabes');
     }
-    return [chars[0], chars[1], chars[2]];
+    return [chars[0], chars[1], chars[2], chars[3]];
   }
 
+  // Les méthodes ci-dessous lèvent une MorphoErreur en cas d'échec
+  // (LETTRE_NON_ARABE si un radical n'est pas une consonne arabe)
+
   // === RACINES ===
   ajouterRacine(racine: string): string {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return ajouter_racine(c1, c2, c3);
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return ajouter_racine(c1, c2, c3, c4);
   }
 
   chercherRacine(racine: string): boolean {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return chercher_racine(c1, c2, c3);
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return chercher_racine(c1, c2, c3, c4);
   }
 
-  supprimerRacine(racine: string): boolean {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return supprimer_racine(c1, c2, c3);
+  supprimerRacine(racine: string): void {
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    supprimer_racine(c1, c2, c3, c4);
   }
 
+  // Nombre de racines ajoutées, sans celles déjà présentes
   chargerRacinesDepuisTexte(contenu: string): number {
     return charger_racines_depuis_texte(contenu);
   }
@@ -101,48 +383,128 @@ export class MorphoService {
     return obtenir_toutes_racines() || [];
   }
 
+  // === RECHERCHES ORDONNÉES (paginées : 50 racines par défaut) ===
+  // Bornes comprises, facultatives : racinesEntre('ك', 'م')
+  racinesEntre(debut?: string, fin?: string, decalage?: number, limite?: number): PageRacines {
+    return racines_entre(debut, fin, decalage, limite);
+  }
+
+  racinesPrefixe(prefixe: string, decalage?: number, limite?: number): PageRacines {
+    return racines_prefixe(prefixe, decalage, limite);
+  }
+
+  // ? = n'importe quel radical : racinesMotif('? ت ب')
+  racinesMotif(motif: string, decalage?: number, limite?: number): PageRacines {
+    return racines_motif(motif, decalage, limite);
+  }
+
+  // Les plus fréquentes dans les textes passés à analyserTexte
+  racinesFrequentes(n: number): RacineInfo[] {
+    return racines_frequentes(n) || [];
+  }
+
+  derivesFrequents(n: number): DeriveFrequent[] {
+    return derives_frequents(n) || [];
+  }
+
+  classeRacine(racine: string): string {
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return classe_racine(c1, c2, c3, c4);
+  }
+
+  // Forme nue d'un mot vocalisé (كَاتِب → كاتب), pour l'affichage
+  sansDiacritiques(mot: string): string {
+    return sans_diacritiques(mot);
+  }
+
   // === DERIVATION (génération seule) ===
   genererDerive(racine: string, schema: string): string {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return generer_derive(c1, c2, c3, schema);
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return generer_derive(c1, c2, c3, c4, schema);
   }
 
   genererTousDerives(racine: string): DeriveInfo[] {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return generer_tous_derives(c1, c2, c3) || [];
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return generer_tous_derives(c1, c2, c3, c4) || [];
+  }
+
+  // Famille triée par catégorie, avec les paires singulier / pluriel brisé
+  genererFamille(racine: string): MembreFamille[] {
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return generer_famille(c1, c2, c3, c4) || [];
   }
 
   // === DERIVATION + STOCKAGE (comme terminal) ===
-  genererEtStockerDerive(racine: string, schema: string): boolean {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return generer_et_stocker_derive(c1, c2, c3, schema);
+  // Retourne le mot généré et stocké
+  genererEtStockerDerive(racine: string, schema: string): string {
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return generer_et_stocker_derive(c1, c2, c3, c4, schema);
   }
 
   genererEtStockerTousDerives(racine: string): number {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return generer_et_stocker_tous_derives(c1, c2, c3);
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return generer_et_stocker_tous_derives(c1, c2, c3, c4);
+  }
+
+  // === CONJUGAISON ===
+  // forme : "I" à "X", "QI", "QII" ou modèle (استفعل) ; voyelles : "a-u", "a-i"...
+  conjuguer(racine: string, forme: string, voyelles?: string): TableConjugaison {
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return conjuguer(c1, c2, c3, c4, forme, voyelles);
   }
 
   // === VALIDATION ===
   validerMotDerive(mot: string, racine: string): ValidationResult {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return valider_mot_derive(mot, c1, c2, c3) || { valide: false, schema: '' };
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return valider_mot_derive(mot, c1, c2, c3, c4) || { valide: false, schema: '', schemes: [] };
+  }
+
+  validerMotComplet(mot: string, racine: string): ValidationComplete {
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return valider_mot_complet(mot, c1, c2, c3, c4)
+      || { mot, valide: false, ambigu: false, lectures: [] };
+  }
+
+  // === ANALYSE INVERSE (mot seul) ===
+  analyserMot(mot: string): AnalyseResult[] {
+    return analyser_mot_derive(mot) || [];
+  }
+
+  // === MOTS AVEC CLITIQUES (والمكتوب، بكاتبه) ===
+  segmenterMot(mot: string): Segmentation[] {
+    return segmenter_mot(mot) || [];
+  }
+
+  validerMotSegmente(mot: string, racine: string): ValidationSegmentee[] {
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return valider_mot_segmente(mot, c1, c2, c3, c4) || [];
+  }
+
+  analyserMotSegmente(mot: string): AnalyseResult[] {
+    return analyser_mot_segmente(mot) || [];
+  }
+
+  // === TEXTE COMPLET ===
+  // top : nombre de racines et de schèmes les plus fréquents (10 par défaut)
+  // Les mots reconnus sont comptés dans les fréquences des racines et dérivés
+  analyserTexte(texte: string, top?: number): AnalyseTexte {
+    return analyser_texte(texte, top);
   }
 
   // === DERIVES STOCKES ===
-  ajouterDeriveARacine(racine: string, mot: string, schema: string): boolean {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return ajouter_derive_a_racine(c1, c2, c3, mot, schema);
+  ajouterDeriveARacine(racine: string, mot: string, schema: string): void {
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    ajouter_derive_a_racine(c1, c2, c3, c4, mot, schema);
   }
 
   obtenirDerivesStockes(racine: string): DeriveInfo[] {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return obtenir_derives_stockes(c1, c2, c3) || [];
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    return obtenir_derives_stockes(c1, c2, c3, c4) || [];
   }
 
-  supprimerDerive(racine: string, mot: string): boolean {
-    const [c1, c2, c3] = this.extraireCaracteres(racine);
-    return supprimer_derive(c1, c2, c3, mot);
+  supprimerDerive(racine: string, mot: string): void {
+    const [c1, c2, c3, c4] = this.extraireCaracteres(racine);
+    supprimer_derive(c1, c2, c3, c4, mot);
   }
 
   // === SCHEMES ===
@@ -150,12 +512,17 @@ export class MorphoService {
     return obtenir_tous_schemes() || [];
   }
 
-  ajouterScheme(nom: string, pattern: string, description: string): boolean {
-    return ajouter_scheme(nom, pattern, description);
+  filtrerSchemes(criteres: CriteresSchemes): SchemeInfo[] {
+    return filtrer_schemes(criteres) || [];
+  }
+
+  // Retourne l'identifiant du schème ajouté ; lève SCHEME_EXISTANT s'il est pris
+  ajouterScheme(nom: string, pattern: string, description: string, options?: OptionsScheme): string {
+    return ajouter_scheme(nom, pattern, description, options);
   }
 
-  supprimerScheme(nom: string): boolean {
-    return supprimer_scheme(nom);
+  supprimerScheme(nom: string): void {
+    supprimer_scheme(nom);
   }
 
   // === SAUVEGARDE / RESTAURATION ===
@@ -163,8 +530,16 @@ export class MorphoService {
     return exporter_donnees();
   }
 
-  importerDonnees(json: string): boolean {
-    return importer_donnees(json);
+  importerDonnees(json: string): void {
+    importer_donnees(json);
+  }
+
+  // Ajoute sans rien effacer ; PolitiqueConflit.Echouer lève CONFLIT_SCHEME
+  fusionnerDonnees(
+    json: string,
+    politique: PolitiqueConflit = PolitiqueConflit.GarderLocal
+  ): RapportFusion {
+    return fusionner_donnees(json, politique);
   }
 
   sauvegarderDansLocalStorage(): void {
@@ -174,9 +549,15 @@ export class MorphoService {
 
   restaurerDepuisLocalStorage(): boolean {
     const json = localStorage.getItem('morpho_data');
-    if (json) {
-      return this.importerDonnees(json);
+    if (!json) {
+      return false;
+    }
+    try {
+      this.importerDonnees(json);
+      return true;
+    } catch (e) {
+      console.error('Sauvegarde locale illisible:', e);
+      return false;
     }
-    return false;
   }
 }
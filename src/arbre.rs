@@ -1,73 +1,315 @@
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Derive {
     pub mot: String,
     pub schema: String,
+    // Traits fléchis d'un mot validé avec sa désinence (كَاتِبُون : masculin
+    // pluriel nominatif) ; absent pour la forme de base du schème
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flexion: Option<Flexion>,
+    // Occurrences dans les textes analysés (voir texte::compter_occurrences)
+    #[serde(default)]
+    pub occurrences: u32,
+}
+
+impl Derive {
+    pub fn new(mot: String, schema: String) -> Derive {
+        Derive {
+            mot,
+            schema,
+            flexion: None,
+            occurrences: 0,
+        }
+    }
 }
 
 // Pour lire un fichier
+use crate::erreur::MorphoError;
+use crate::flexion::Flexion;
+use crate::normalisation::equivalent;
+use crate::racine::Racine;
+use serde::{Deserialize, Serialize};
+use std::cmp::{Reverse, max};
 use std::fs;
 
+// Nœud de l'arbre AVL des racines
+// `hauteur` = hauteur du sous-arbre enraciné ici (une feuille a la hauteur 1)
 pub struct RacineNode {
-    pub racine: [char; 3],
+    pub racine: Racine,
     pub derives: Vec<Derive>, // liste des mots dérivés validés
-    pub frequence: u32,       // nombre de dérivés stockés
+    pub frequence: u32,       // occurrences dans les textes analysés
+    pub hauteur: i32,         // hauteur du sous-arbre (pour l'équilibrage AVL)
     pub left: Option<Box<RacineNode>>,
     pub right: Option<Box<RacineNode>>,
 }
 
-pub fn morphologic_cmp(tree_racine: [char; 3], racine: [char; 3]) -> i8 {
+// Comparer deux racines lettre par lettre (ordre alphabétique arabe)
+// Si les 3 premières lettres sont égales, la racine trilitère passe avant
+// la quadrilitère (ex: ز ل ز < ز ل ز ل)
+// La comparaison porte sur les clés normalisées (voir normalisation.rs) :
+// أ ك ل et ء ك ل sont la même racine, le nœud garde l'écriture insérée en premier
+// Retourne -1 si tree_racine > racine (aller à gauche), 1 si tree_racine < racine, 0 si égales
+pub fn morphologic_cmp(tree_racine: Racine, racine: Racine) -> i8 {
+    let (cle_arbre, cle) = (tree_racine.cle(), racine.cle());
+    let a = cle_arbre.lettres();
+    let b = cle.lettres();
     let mut i = 0;
-    while i < 3 {
-        if tree_racine[i] == racine[i] {
-            i = i + 1;
+    while i < a.len() && i < b.len() {
+        if a[i] == b[i] {
+            i += 1;
+        } else if a[i] > b[i] {
+            return -1;
         } else {
-            if tree_racine[i] > racine[i] {
-                return -1;
-            } else {
-                return 1;
-            }
+            return 1;
         }
     }
-    return 0;
+    if a.len() > b.len() {
+        -1
+    } else if a.len() < b.len() {
+        1
+    } else {
+        0
+    }
 }
 
 pub struct Tree {
     pub racine: Option<Box<RacineNode>>,
 }
 
+// ========================================================
+// ÉQUILIBRAGE AVL
+// ========================================================
+// Invariant : pour chaque nœud, |hauteur(gauche) - hauteur(droite)| <= 1
+// Cela garantit une hauteur en O(log n), même si les racines arrivent triées
+// (cas typique d'un fichier lexique exporté par ordre alphabétique).
+
+// Hauteur d'un sous-arbre (0 pour un sous-arbre vide)
+fn hauteur(node: &Option<Box<RacineNode>>) -> i32 {
+    match node {
+        Some(n) => n.hauteur,
+        None => 0,
+    }
+}
+
+// Recalculer la hauteur d'un nœud à partir de ses enfants
+fn mettre_a_jour_hauteur(node: &mut RacineNode) {
+    node.hauteur = 1 + max(hauteur(&node.left), hauteur(&node.right));
+}
+
+// Facteur d'équilibre : > 0 si le sous-arbre gauche est plus haut
+fn facteur_equilibre(node: &RacineNode) -> i32 {
+    hauteur(&node.left) - hauteur(&node.right)
+}
+
+// Rotation droite :
+//        y              x
+//       / \            / \
+//      x   C   ==>    A   y
+//     / \                / \
+//    A   B              B   C
+fn rotation_droite(mut y: Box<RacineNode>) -> Box<RacineNode> {
+    let mut x = y.left.take().expect("rotation droite sans enfant gauche");
+    y.left = x.right.take();
+    mettre_a_jour_hauteur(&mut y);
+    x.right = Some(y);
+    mettre_a_jour_hauteur(&mut x);
+    x
+}
+
+// Rotation gauche (symétrique de la rotation droite)
+fn rotation_gauche(mut x: Box<RacineNode>) -> Box<RacineNode> {
+    let mut y = x.right.take().expect("rotation gauche sans enfant droit");
+    x.right = y.left.take();
+    mettre_a_jour_hauteur(&mut x);
+    y.left = Some(x);
+    mettre_a_jour_hauteur(&mut y);
+    y
+}
+
+// Rééquilibrer un nœud après une insertion ou une suppression dans l'un de ses sous-arbres
+// Retourne la nouvelle racine du sous-arbre
+fn reequilibrer(mut node: Box<RacineNode>) -> Box<RacineNode> {
+    mettre_a_jour_hauteur(&mut node);
+    let facteur = facteur_equilibre(&node);
+
+    if facteur > 1 {
+        // Trop lourd à gauche : cas gauche-droite → rotation gauche de l'enfant d'abord
+        if facteur_equilibre(node.left.as_ref().unwrap()) < 0 {
+            node.left = Some(rotation_gauche(node.left.take().unwrap()));
+        }
+        return rotation_droite(node);
+    }
+
+    if facteur < -1 {
+        // Trop lourd à droite : cas droite-gauche → rotation droite de l'enfant d'abord
+        if facteur_equilibre(node.right.as_ref().unwrap()) > 0 {
+            node.right = Some(rotation_droite(node.right.take().unwrap()));
+        }
+        return rotation_gauche(node);
+    }
+
+    node
+}
+
+// Insérer récursivement dans un sous-arbre puis rééquilibrer en remontant
+// Retourne false pour un doublon (l'arbre est inchangé)
+fn inserer_node(node: &mut Option<Box<RacineNode>>, ch: Racine) -> bool {
+    let mut current = match node.take() {
+        Some(n) => n,
+        None => {
+            *node = Some(Box::new(RacineNode::new(ch)));
+            return true;
+        }
+    };
+
+    let cmp = morphologic_cmp(current.racine, ch);
+    let inseree;
+
+    if cmp == 0 {
+        *node = Some(current); // doublon : rien à faire
+        return false;
+    } else if cmp == -1 {
+        inseree = inserer_node(&mut current.left, ch);
+    } else {
+        inseree = inserer_node(&mut current.right, ch);
+    }
+
+    *node = Some(reequilibrer(current));
+    inseree
+}
+
+// Détacher le minimum d'un sous-arbre
+// Retourne (sous-arbre restant rééquilibré, nœud minimum détaché)
+fn extraire_min(mut node: Box<RacineNode>) -> (Option<Box<RacineNode>>, Box<RacineNode>) {
+    match node.left.take() {
+        None => {
+            let reste = node.right.take();
+            (reste, node)
+        }
+        Some(gauche) => {
+            let (reste, min) = extraire_min(gauche);
+            node.left = reste;
+            (Some(reequilibrer(node)), min)
+        }
+    }
+}
+
+// Supprimer récursivement dans un sous-arbre puis rééquilibrer en remontant
+fn supprimer_node(node: &mut Option<Box<RacineNode>>, ch: Racine) -> bool {
+    let mut current = match node.take() {
+        Some(n) => n,
+        None => return false, // Noeud non trouvé
+    };
+
+    let cmp = morphologic_cmp(current.racine, ch);
+    let found;
+
+    if cmp == 0 {
+        // Noeud trouvé, gérer les 3 cas de suppression
+        *node = match (current.left.take(), current.right.take()) {
+            (None, None) => None,               // Cas 1: aucun enfant
+            (Some(left), None) => Some(left),   // Cas 2: uniquement enfant gauche
+            (None, Some(right)) => Some(right), // Cas 2: uniquement enfant droit
+            (Some(left), Some(right)) => {
+                // Cas 3: deux enfants - remplacer par le successeur (min du sous-arbre droit)
+                let (reste, mut successeur) = extraire_min(right);
+                successeur.left = Some(left);
+                successeur.right = reste;
+                Some(reequilibrer(successeur))
+            }
+        };
+        return true;
+    } else if cmp == -1 {
+        // Chercher à gauche
+        found = supprimer_node(&mut current.left, ch);
+    } else {
+        // Chercher à droite
+        found = supprimer_node(&mut current.right, ch);
+    }
+
+    *node = Some(reequilibrer(current));
+    found
+}
+
 impl RacineNode {
-    pub fn new(racine: [char; 3]) -> Self {
+    pub fn new(racine: Racine) -> Self {
         RacineNode {
             racine,
             derives: Vec::new(), // liste vide au début
-            frequence: 0,        // aucun dérivé au début
+            frequence: 0,        // jamais rencontrée dans un texte
+            hauteur: 1,          // une feuille
             left: None,
             right: None,
         }
     }
 
     // Ajouter un dérivé validé à ce nœud
-    pub fn ajouter_derive(&mut self, mot: String, schema: String) {
+    // Retourne false si le dérivé était déjà présent (rien n'a changé)
+    pub fn ajouter_derive(&mut self, mot: String, schema: String) -> bool {
+        self.ajouter(Derive::new(mot, schema))
+    }
+
+    // Comme ajouter_derive, avec les traits fléchis et les occurrences du dérivé
+    pub fn ajouter(&mut self, derive: Derive) -> bool {
         // Vérifier si ce dérivé existe déjà (éviter les doublons)
-        for d in &self.derives {
-            if d.mot == mot {
-                return; // déjà présent, on ne fait rien
+        // Une forme nue et sa forme vocalisée (كاتب / كَاتِب) du même schème
+        // sont le même dérivé : on garde la plus détaillée (et ses occurrences)
+        for d in &mut self.derives {
+            if d.mot == derive.mot {
+                return false; // déjà présent, on ne fait rien
+            }
+            if d.schema == derive.schema && d.flexion == derive.flexion {
+                if equivalent(&d.mot, &derive.mot) {
+                    d.mot = derive.mot;
+                    d.occurrences = d.occurrences.max(derive.occurrences);
+                    return true;
+                }
+                if equivalent(&derive.mot, &d.mot) {
+                    return false;
+                }
             }
         }
         // Ajouter le nouveau dérivé
-        self.derives.push(Derive {
-            mot: mot,
-            schema: schema,
-        });
-        self.frequence = self.frequence + 1;
+        self.derives.push(derive);
+        true
+    }
+
+    // Compter une occurrence de la racine dans un texte
+    pub fn attester(&mut self) {
+        self.frequence += 1;
+    }
+
+    // Compter une occurrence du dérivé stocké de ce schème et de ces traits
+    // fléchis ; retourne false si aucun dérivé stocké ne correspond (à
+    // l'appelant de le stocker, voir texte::compter_occurrences)
+    pub fn attester_derive(&mut self, schema: &str, flexion: Option<Flexion>) -> bool {
+        match self
+            .derives
+            .iter_mut()
+            .find(|d| d.schema == schema && d.flexion == flexion)
+        {
+            Some(d) => {
+                d.occurrences += 1;
+                true
+            }
+            None => false,
+        }
     }
 
     // Supprimer un dérivé spécifique de ce nœud
     pub fn supprimer_derive(&mut self, mot: &str) -> bool {
-        // Chercher le dérivé par son mot
-        if let Some(pos) = self.derives.iter().position(|d| d.mot == mot) {
+        // Chercher le dérivé par son mot ; à défaut, la seule forme vocalisée
+        // qui correspond à la saisie (كاتب supprime كَاتِب s'il n'y a pas aussi كَاتَبَ)
+        let exact = self.derives.iter().position(|d| d.mot == mot);
+        let proches: Vec<usize> = (0..self.derives.len())
+            .filter(|&i| equivalent(mot, &self.derives[i].mot))
+            .collect();
+        let pos = exact.or(if proches.len() == 1 {
+            Some(proches[0])
+        } else {
+            None
+        });
+        if let Some(pos) = pos {
             self.derives.remove(pos);
-            self.frequence = self.frequence - 1;
             return true;
         }
         false // Dérivé non trouvé
@@ -75,10 +317,27 @@ impl RacineNode {
 
     // Afficher tous les dérivés de cette racine
     pub fn afficher_derives(&self) {
-        let r: String = self.racine.iter().collect();
-        println!("Racine: {} ({} dérivés)", r, self.frequence);
+        println!(
+            "Racine: {} ({} dérivés, {} occurrences)",
+            self.racine,
+            self.derives.len(),
+            self.frequence
+        );
         for d in &self.derives {
-            println!("  - {} (schème: {})", d.mot, d.schema);
+            let occurrences = match d.occurrences {
+                0 => String::new(),
+                n => format!(", {} occurrences", n),
+            };
+            match d.flexion {
+                Some(flexion) => println!(
+                    "  - {} (schème: {}, {}{})",
+                    d.mot,
+                    d.schema,
+                    flexion.etiquette(),
+                    occurrences
+                ),
+                None => println!("  - {} (schème: {}{})", d.mot, d.schema, occurrences),
+            }
         }
     }
 
@@ -91,11 +350,10 @@ impl RacineNode {
         }
 
         // 2) Ensuite, afficher le nœud courant
-        let r: String = self.racine.iter().collect();
-        if self.frequence > 0 {
-            println!("  {} ({} dérivés)", r, self.frequence);
-        } else {
-            println!("  {}", r);
+        match (self.derives.len(), self.frequence) {
+            (0, 0) => println!("  {}", self.racine),
+            (n, 0) => println!("  {} ({} dérivés)", self.racine, n),
+            (n, f) => println!("  {} ({} dérivés, {} occurrences)", self.racine, n, f),
         }
 
         // 3) Enfin, afficher tout le sous-arbre droit
@@ -105,7 +363,7 @@ impl RacineNode {
     }
 
     // Collecter toutes les racines dans un vecteur (pour l'API WebAssembly)
-    pub fn collecter_racines_node(&self, resultat: &mut Vec<([char; 3], u32, Vec<Derive>)>) {
+    pub fn collecter_racines_node(&self, resultat: &mut Vec<(Racine, u32, Vec<Derive>)>) {
         // Parcours in-order : gauche → nœud → droite
         if let Some(gauche) = &self.left {
             gauche.collecter_racines_node(resultat);
@@ -118,122 +376,103 @@ impl RacineNode {
         }
     }
 
-    pub fn verify_node(&self, ch: [char; 3]) -> bool {
+    pub fn verify_node(&self, ch: Racine) -> bool {
         let cmp = morphologic_cmp(self.racine, ch);
         if cmp == 0 {
-            return true;
+            true
+        } else if cmp == -1 {
+            match &self.left {
+                Some(gauche) => gauche.verify_node(ch),
+                None => false,
+            }
         } else {
-            if cmp == -1 {
-                if self.left.is_none() {
-                    return false;
-                } else {
-                    return self.left.as_ref().unwrap().verify_node(ch);
-                }
-            } else {
-                if self.right.is_none() {
-                    return false;
-                } else {
-                    return self.right.as_ref().unwrap().verify_node(ch);
-                }
+            match &self.right {
+                Some(droite) => droite.verify_node(ch),
+                None => false,
             }
         }
     }
-    pub fn insert_node(&mut self, ch: [char; 3]) {
-        let cmp = morphologic_cmp(self.racine, ch);
-        if cmp == 1 {
-            if self.right.is_none() {
-                self.right = Some(Box::new(RacineNode::new(ch)));
-            } else {
-                self.right.as_mut().unwrap().insert_node(ch);
-            }
-        } else {
-            if cmp == 0 {
-                return;
-            } else {
-                if self.left.is_none() {
-                    self.left = Some(Box::new(RacineNode::new(ch)));
-                } else {
-                    self.left.as_mut().unwrap().insert_node(ch);
-                }
-            }
+
+    // Vérifier récursivement les invariants AVL de ce sous-arbre
+    // Retourne la hauteur réelle du sous-arbre, ou None si un invariant est violé
+    // (hauteur stockée fausse, déséquilibre > 1, ou ordre des racines non respecté)
+    fn verifier_avl(&self, borne_min: Option<Racine>, borne_max: Option<Racine>) -> Option<i32> {
+        if let Some(borne) = borne_min
+            && morphologic_cmp(borne, self.racine) != 1
+        {
+            return None;
+        }
+        if let Some(borne) = borne_max
+            && morphologic_cmp(borne, self.racine) != -1
+        {
+            return None;
+        }
+
+        let hg = match &self.left {
+            Some(g) => g.verifier_avl(borne_min, Some(self.racine))?,
+            None => 0,
+        };
+        let hd = match &self.right {
+            Some(d) => d.verifier_avl(Some(self.racine), borne_max)?,
+            None => 0,
+        };
+
+        let h = 1 + max(hg, hd);
+        if (hg - hd).abs() > 1 || h != self.hauteur {
+            return None;
         }
+        Some(h)
     }
 }
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Tree {
     pub fn new() -> Self {
         Tree { racine: None }
     }
-    pub fn verify(&self, ch: [char; 3]) -> bool {
-        if self.racine.is_none() {
-            return false;
-        }
-        let node = self.racine.as_ref().unwrap();
-        return node.verify_node(ch);
-    }
-    pub fn insert(&mut self, ch: [char; 3]) {
-        if self.racine.is_none() {
-            self.racine = Some(Box::new(RacineNode::new(ch)));
-            return;
-        }
-        self.racine.as_mut().unwrap().insert_node(ch);
-    }
-
-    // Supprimer une racine de l'arbre
-    pub fn delete(&mut self, ch: [char; 3]) -> bool {
-        // Fonction auxiliaire récursive pour supprimer un noeud
-        fn delete_node(node: &mut Option<Box<RacineNode>>, ch: [char; 3]) -> bool {
-            if let Some(mut current) = node.take() {
-                let cmp = morphologic_cmp(current.racine, ch);
-
-                if cmp == 0 {
-                    // Noeud trouvé, gérer les 3 cas de suppression
-                    *node = match (current.left.take(), current.right.take()) {
-                        (None, None) => None,               // Cas 1: aucun enfant
-                        (Some(left), None) => Some(left),   // Cas 2: uniquement enfant gauche
-                        (None, Some(right)) => Some(right), // Cas 2: uniquement enfant droit
-                        (Some(left), Some(right)) => {
-                            // Cas 3: deux enfants - remplacer par le successeur (min du sous-arbre droit)
-                            let mut successor_parent = right;
-                            if successor_parent.left.is_none() {
-                                // Le sous-arbre droit n'a pas d'enfant gauche
-                                successor_parent.left = Some(left);
-                                Some(successor_parent)
-                            } else {
-                                // Trouver le minimum du sous-arbre droit
-                                let mut parent = &mut successor_parent;
-                                while parent.left.as_ref().unwrap().left.is_some() {
-                                    parent = parent.left.as_mut().unwrap();
-                                }
-                                let mut successor = parent.left.take().unwrap();
-                                parent.left = successor.right.take();
-                                successor.left = Some(left);
-                                successor.right = Some(successor_parent);
-                                Some(successor)
-                            }
-                        }
-                    };
-                    return true;
-                } else if cmp == -1 {
-                    // Chercher à gauche
-                    let found = delete_node(&mut current.left, ch);
-                    *node = Some(current);
-                    return found;
-                } else {
-                    // Chercher à droite
-                    let found = delete_node(&mut current.right, ch);
-                    *node = Some(current);
-                    return found;
-                }
-            }
-            false // Noeud non trouvé
+    pub fn verify(&self, ch: Racine) -> bool {
+        match &self.racine {
+            Some(node) => node.verify_node(ch),
+            None => false,
+        }
+    }
+    // Insérer une racine ; false si elle était déjà dans l'arbre
+    pub fn insert(&mut self, ch: Racine) -> bool {
+        // L'insertion reconstruit le chemin depuis la racine en le rééquilibrant
+        inserer_node(&mut self.racine, ch)
+    }
+
+    // Supprimer une racine de l'arbre (l'arbre reste équilibré)
+    pub fn delete(&mut self, ch: Racine) -> Result<(), MorphoError> {
+        if supprimer_node(&mut self.racine, ch) {
+            Ok(())
+        } else {
+            Err(MorphoError::RacineIntrouvable(ch))
         }
+    }
 
-        delete_node(&mut self.racine, ch)
+    // Hauteur de l'arbre (0 si vide) : reste en O(log n) grâce à l'équilibrage AVL
+    pub fn hauteur(&self) -> i32 {
+        hauteur(&self.racine)
+    }
+
+    // Vérifier que l'arbre respecte tous les invariants AVL
+    // (utile pour les tests après un chargement massif de racines)
+    pub fn est_equilibre(&self) -> bool {
+        match &self.racine {
+            Some(node) => node.verifier_avl(None, None).is_some(),
+            None => true,
+        }
     }
 
     // Chercher un noeud par sa racine et retourner une référence mutable
     // On en a besoin pour pouvoir ajouter des dérivés à un noeud
-    pub fn chercher_noeud(&mut self, ch: [char; 3]) -> Option<&mut RacineNode> {
+    pub fn chercher_noeud(&mut self, ch: Racine) -> Option<&mut RacineNode> {
         // Commencer à la racine de l'arbre
         let mut courant = self.racine.as_mut();
 
@@ -250,80 +489,180 @@ impl Tree {
         None // pas trouvé
     }
 
+    // Chercher un noeud, avec une erreur si la racine n'est pas dans l'arbre
+    pub fn noeud(&mut self, ch: Racine) -> Result<&mut RacineNode, MorphoError> {
+        self.chercher_noeud(ch)
+            .ok_or(MorphoError::RacineIntrouvable(ch))
+    }
+
     // Ajouter un dérivé à une racine donnée (cherche le noeud puis ajoute)
-    pub fn ajouter_derive(&mut self, ch: [char; 3], mot: String, schema: String) -> bool {
+    // Un dérivé déjà présent est ignoré sans erreur
+    pub fn ajouter_derive(
+        &mut self,
+        ch: Racine,
+        mot: String,
+        schema: String,
+    ) -> Result<(), MorphoError> {
         // D'abord on cherche le noeud de cette racine
-        let noeud = self.chercher_noeud(ch);
-        match noeud {
-            Some(n) => {
-                n.ajouter_derive(mot, schema);
-                true // succès
-            }
-            None => false, // racine non trouvée dans l'arbre
+        self.noeud(ch)?.ajouter_derive(mot, schema);
+        Ok(())
+    }
+
+    // Supprimer un dérivé d'une racine donnée
+    pub fn supprimer_derive(&mut self, ch: Racine, mot: &str) -> Result<(), MorphoError> {
+        if self.noeud(ch)?.supprimer_derive(mot) {
+            Ok(())
+        } else {
+            Err(MorphoError::DeriveIntrouvable {
+                racine: ch,
+                mot: mot.to_string(),
+            })
         }
     }
 
     // Charger des racines depuis un fichier texte
-    // Le fichier contient une racine par ligne, format : "ك ت ب"
-    // Retourne le nombre de racines chargées
-    pub fn charger_depuis_fichier(&mut self, chemin: &str) -> u32 {
-        // Étape 1 : Lire tout le contenu du fichier
-        let contenu = fs::read_to_string(chemin);
-
-        // Étape 2 : Vérifier si la lecture a réussi
-        let texte = match contenu {
-            Ok(t) => t, // lecture OK, on récupère le texte
-            Err(e) => {
-                println!("Erreur lecture fichier '{}': {}", chemin, e);
-                return 0; // on retourne 0 racines chargées
-            }
-        };
+    // Le fichier contient une racine par ligne, format : "ك ت ب" ou "ت ر ج م"
+    // Retourne le nombre de racines ajoutées (sans les doublons)
+    pub fn charger_depuis_fichier(&mut self, chemin: &str) -> Result<u32, MorphoError> {
+        let texte = fs::read_to_string(chemin).map_err(|e| MorphoError::Fichier {
+            chemin: chemin.to_string(),
+            message: e.to_string(),
+        })?;
+        self.charger_depuis_texte(&texte)
+    }
 
-        let mut compteur: u32 = 0;
+    // Charger des racines depuis un texte (une racine par ligne, lignes vides ignorées)
+    // Tout le texte est vérifié avant d'insérer : une ligne invalide n'insère rien
+    // et l'erreur indique son numéro
+    pub fn charger_depuis_texte(&mut self, texte: &str) -> Result<u32, MorphoError> {
+        let mut racines = Vec::new();
 
-        // Étape 3 : Parcourir le fichier ligne par ligne
-        for ligne in texte.lines() {
-            // Ignorer les lignes vides
+        for (i, ligne) in texte.lines().enumerate() {
             let ligne = ligne.trim();
             if ligne.is_empty() {
                 continue;
             }
 
-            // Étape 4 : Extraire les 3 caractères arabes de la ligne
-            // Format attendu : "ك ت ب" (3 caractères séparés par des espaces)
-            let chars: Vec<char> = ligne
-                .chars() // itérer sur chaque caractère
-                .filter(|c| !c.is_whitespace()) // enlever les espaces
-                .collect(); // collecter dans un vecteur
-
-            // Vérifier qu'on a bien 3 caractères
-            if chars.len() == 3 {
-                let racine: [char; 3] = [chars[0], chars[1], chars[2]];
-                self.insert(racine); // insérer dans l'arbre (les doublons sont ignorés)
-                compteur = compteur + 1;
-            } else {
-                println!("Ligne ignorée (pas 3 caractères): '{}'", ligne);
-            }
+            // Format attendu : "ك ت ب" (caractères séparés ou non par des espaces)
+            let racine = Racine::depuis_texte(ligne).map_err(|e| MorphoError::Syntaxe {
+                ligne: i + 1,
+                message: format!("'{}' : {}", ligne, e),
+            })?;
+            racines.push(racine);
         }
 
-        println!("{} racines chargées depuis '{}'", compteur, chemin);
-        compteur
+        // Les doublons (dans le texte ou déjà dans l'arbre) ne sont pas comptés
+        let ajoutees = racines.iter().filter(|r| self.insert(**r)).count();
+        Ok(ajoutees as u32)
     }
 
     // Afficher toutes les racines de l'arbre (parcours in-order)
     pub fn afficher(&self) {
-        if self.racine.is_none() {
-            println!("L'arbre est vide.");
-            return;
+        match &self.racine {
+            Some(root) => {
+                println!("=== Racines stockées (ordre trié) ===");
+                root.afficher_in_order();
+            }
+            None => println!("L'arbre est vide."),
         }
-        println!("=== Racines stockées (ordre trié) ===");
-        self.racine.as_ref().unwrap().afficher_in_order();
     }
 
     // Collecter toutes les racines de l'arbre (pour l'API WebAssembly)
-    pub fn collecter_racines(&self, resultat: &mut Vec<([char; 3], u32, Vec<Derive>)>) {
+    pub fn collecter_racines(&self, resultat: &mut Vec<(Racine, u32, Vec<Derive>)>) {
         if let Some(ref root) = self.racine {
             root.collecter_racines_node(resultat);
         }
     }
+
+    // Les `n` racines les plus fréquentes dans les textes analysés, avec leur
+    // fréquence ; les racines jamais rencontrées sont exclues
+    // À égalité, l'ordre alphabétique de l'arbre est gardé
+    pub fn racines_frequentes(&self, n: usize) -> Vec<(Racine, u32, Vec<Derive>)> {
+        let mut racines = Vec::new();
+        self.collecter_racines(&mut racines);
+        racines.retain(|(_, frequence, _)| *frequence > 0);
+        racines.sort_by_key(|r| Reverse(r.1));
+        racines.truncate(n);
+        racines
+    }
+
+    // Les `n` dérivés stockés les plus fréquents, avec leur racine
+    pub fn derives_frequents(&self, n: usize) -> Vec<(Racine, Derive)> {
+        let mut racines = Vec::new();
+        self.collecter_racines(&mut racines);
+        let mut derives: Vec<(Racine, Derive)> = racines
+            .into_iter()
+            .flat_map(|(racine, _, derives)| derives.into_iter().map(move |d| (racine, d)))
+            .filter(|(_, d)| d.occurrences > 0)
+            .collect();
+        derives.sort_by_key(|(_, d)| Reverse(d.occurrences));
+        derives.truncate(n);
+        derives
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::racine::LETTRES_RADICALES;
+
+    // Racines trilitères distinctes, dans l'ordre de l'arbre (clés repliées)
+    fn racines_triees(nombre: usize) -> Vec<Racine> {
+        let mut lettres: Vec<char> = LETTRES_RADICALES
+            .iter()
+            .map(|c| Racine::cle_lettre(*c))
+            .collect();
+        lettres.sort();
+        lettres.dedup();
+
+        let mut racines = Vec::new();
+        for &c1 in &lettres {
+            for &c2 in &lettres {
+                for &c3 in &lettres {
+                    racines.push(Racine::depuis_lettres(&[c1, c2, c3]).unwrap());
+                }
+            }
+        }
+        racines.sort_by(|a, b| a.cle().lettres().cmp(b.cle().lettres()));
+        racines.truncate(nombre);
+        racines
+    }
+
+    // Borne de hauteur d'un AVL de n nœuds
+    fn hauteur_max(n: usize) -> f64 {
+        1.44 * ((n + 2) as f64).log2()
+    }
+
+    #[test]
+    fn insertions_triees_puis_suppression_de_la_moitie() {
+        let racines = racines_triees(5000);
+        assert_eq!(racines.len(), 5000);
+
+        let mut arbre = Tree::new();
+        for racine in &racines {
+            arbre.insert(*racine);
+        }
+        assert!(arbre.est_equilibre());
+        assert!(arbre.hauteur() as f64 <= hauteur_max(racines.len()));
+
+        // Une racine sur deux
+        for racine in racines.iter().step_by(2) {
+            arbre.delete(*racine).unwrap();
+        }
+        let restantes = racines.len() / 2;
+        assert!(arbre.est_equilibre());
+        assert!(arbre.hauteur() as f64 <= hauteur_max(restantes));
+
+        for (i, racine) in racines.iter().enumerate() {
+            assert_eq!(arbre.verify(*racine), i % 2 == 1);
+        }
+    }
+
+    #[test]
+    fn chargement_sans_compter_les_doublons() {
+        let mut arbre = Tree::new();
+        assert_eq!(arbre.charger_depuis_texte("ك ت ب\nد ر س\nكتب\n"), Ok(2));
+        assert_eq!(arbre.charger_depuis_texte("د ر س\nق و ل"), Ok(1));
+        assert!(!arbre.insert(Racine::depuis_texte("ق و ل").unwrap()));
+    }
 }
@@ -0,0 +1,1086 @@
+// ============================================================================
+// INTERFACE EN LIGNE DE COMMANDE (non interactive)
+// ============================================================================
+// Pour les scripts : une sous-commande par opération, sortie sans décoration.
+//
+//   moteur_morphologique generer --racine "ك ت ب" --scheme فاعل
+//   moteur_morphologique valider --mot مكتوب --racine "ك ت ب" --format json
+//   moteur_morphologique famille --racine "ك ت ب" --format tsv
+//   moteur_morphologique analyser --mot مستخرج --racines racines.txt
+//   moteur_morphologique segmenter --mot والمكتوب
+//   moteur_morphologique texte article.txt --racines racines.txt --vue statistiques
+//   moteur_morphologique frequences --vue derives --top 20 --lexique moi.json
+//   moteur_morphologique racines --motif "? ت ب" --limite 20 --decalage 40
+//   moteur_morphologique conjuguer --racine "ق و ل" --forme I --format json
+//   moteur_morphologique charger racines.txt
+//   moteur_morphologique importer export.json --politique local --lexique moi.json
+//   moteur_morphologique schemes list | add --nom .. --pattern .. | remove --nom ..
+//   moteur_morphologique repl          (menu interactif)
+//
+// Option globale `--lexique fichier.json` : l'état (racines, dérivés, schèmes)
+// est lu depuis ce fichier et réécrit après chaque commande qui le modifie
+// (charger, importer, texte, schemes add/remove). Voir lexique.rs pour le format.
+//
+// Codes de sortie :
+//   0 succès, 1 résultat négatif (mot non valide, aucune analyse),
+//   2 erreur d'utilisation (argument manquant ou inconnu), 3 erreur du moteur
+
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use moteur_morphologique::arbre::Tree;
+use moteur_morphologique::conjugaison::{VoyellesI, conjuguer, forme_depuis_texte};
+use moteur_morphologique::erreur::MorphoError;
+use moteur_morphologique::gabarit::sans_diacritiques;
+use moteur_morphologique::hashing::{Categorie, Scheme, SchemeTable, init_schemes};
+use moteur_morphologique::lexique::{Lexique, PolitiqueConflit};
+use moteur_morphologique::morpho_analyzer::{
+    analyser_mot_segmente, generer_famille, generer_par_nom, valider_mot_segmente,
+};
+use moteur_morphologique::phonologie::classifier;
+use moteur_morphologique::proprietes::{
+    Aspect, Criteres, Genre, Nature, Nombre, Proprietes, Voix, forme_et_systeme,
+};
+use moteur_morphologique::racine::Racine;
+use moteur_morphologique::requete::{LIMITE_PAGE, Pagination};
+use moteur_morphologique::segmentation::{Clitique, segmenter};
+use moteur_morphologique::texte::{TOP_FREQUENCES, analyser_texte, compter_occurrences};
+use serde_json::{Map, Value};
+
+pub const SUCCES: i32 = 0;
+pub const NEGATIF: i32 = 1;
+pub const USAGE: i32 = 2;
+pub const ERREUR: i32 = 3;
+
+pub const AIDE: &str = "\
+Usage : moteur_morphologique <commande> [options] [--format text|json|tsv] [--lexique fichier]
+
+Commandes :
+  generer  --racine \"ك ت ب\" --scheme فاعل    générer un dérivé
+  valider  --mot مكتوب --racine \"ك ت ب\"      valider un mot (code 1 si non valide)
+  famille  --racine \"ك ت ب\"                  tous les dérivés d'une racine
+  analyser --mot مستخرج [--racines fichier]   retrouver racine et schème (code 1 si aucun)
+  segmenter --mot والمكتوب                   découpes en clitiques (و، ف، ب، ك، ل، س، ال,
+                                             pronoms suffixes) ; valider et analyser
+                                             travaillent sur chaque base
+  texte    [fichier|-] [--racines fichier]   analyser chaque mot d'un texte (entrée
+           [--vue jetons|statistiques]       standard sans fichier) ; statistiques :
+           [--top N]                         couverture, racines et schèmes fréquents
+                                             (code 1 si aucun mot analysé) ; les mots
+                                             reconnus sont comptés dans le lexique
+  frequences [--vue racines|derives]         racines ou dérivés stockés les plus
+             [--top N]                       fréquents dans les textes (code 1 si aucun)
+  conjuguer --racine \"ك ت ب\" --forme I       tableau de conjugaison (forme : I à X, QI, QII,
+            [--voyelles a-u]                 modèle ou schème ; voyelles : باب de la forme I)
+  racines  [--debut ك] [--fin م]             racines de l'arbre, dans l'ordre, entre deux
+           | --prefixe \"ك ت\"                 bornes, par préfixe ou par motif (? = tout
+           | --motif \"? ت ب\"                  radical) ; pagination : --limite N (50)
+           [--decalage N] [--limite N]        et --decalage N (code 1 si page vide)
+  charger  <fichier>                         lire un fichier de racines
+  importer <fichier> [--politique P]         fusionner un export/lexique JSON
+                                             (conflit de schème : local, importe ou echec)
+  schemes  list [filtres]                    lister les schèmes (code 1 si aucun)
+  schemes  add --nom X --pattern P [--description D] [--id ID] [propriétés]
+                                             ID : clé unique, le nom par défaut
+           [--pluriels \"مفاعل,فعول\"]          pluriels : clés des schèmes de pluriel brisé
+  schemes  remove --nom X
+  repl                                       menu interactif
+  aide                                       afficher cette aide
+
+Filtres et propriétés des schèmes :
+  --forme I..XV|QI..QIV   --nature nom|adjectif|verbe|masdar|participe
+  --voix actif|passif   --aspect accompli|inaccompli|imperatif   --nombre singulier|duel|pluriel
+  --genre masculin|feminin   --radicaux 3|4, --modele فاعل (filtres seulement)
+  --categorie verbe|participe|masdar|nom|feminin|nisba|nom_de_lieu|nom_d_instrument|
+              pluriel_brise|autre
+
+--lexique fichier.json : lire l'état depuis ce fichier et le sauvegarder après
+chaque modification (créé s'il n'existe pas ; utilisable aussi avec repl)
+
+Codes de sortie : 0 succès, 1 résultat négatif, 2 erreur d'utilisation, 3 erreur du moteur";
+
+// ============================================================================
+// ARGUMENTS
+// ============================================================================
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Texte,
+    Json,
+    Tsv,
+}
+
+// Ligne de commande découpée : positionnels + options "--cle valeur" / "--cle=valeur"
+struct Arguments {
+    positionnels: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+enum ErreurCli {
+    Usage(String),
+    Moteur(MorphoError),
+}
+
+impl From<MorphoError> for ErreurCli {
+    fn from(e: MorphoError) -> Self {
+        ErreurCli::Moteur(e)
+    }
+}
+
+impl Arguments {
+    fn analyser(args: &[String]) -> Result<Arguments, ErreurCli> {
+        let mut positionnels = Vec::new();
+        let mut options = HashMap::new();
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            let Some(option) = arg.strip_prefix("--") else {
+                positionnels.push(arg.clone());
+                continue;
+            };
+            let (cle, valeur) = match option.split_once('=') {
+                Some((cle, valeur)) => (cle.to_string(), valeur.to_string()),
+                None => match iter.next() {
+                    Some(valeur) => (option.to_string(), valeur.clone()),
+                    None => {
+                        return Err(ErreurCli::Usage(format!(
+                            "l'option --{} attend une valeur",
+                            option
+                        )));
+                    }
+                },
+            };
+            options.insert(cle, valeur);
+        }
+
+        Ok(Arguments {
+            positionnels,
+            options,
+        })
+    }
+
+    // Option obligatoire
+    fn requise(&self, cle: &str) -> Result<&str, ErreurCli> {
+        self.options
+            .get(cle)
+            .map(|v| v.as_str())
+            .ok_or_else(|| ErreurCli::Usage(format!("option --{} manquante", cle)))
+    }
+
+    fn optionnelle(&self, cle: &str) -> Option<&str> {
+        self.options.get(cle).map(|v| v.as_str())
+    }
+
+    // Refuser les options qu'une commande ne connaît pas (fautes de frappe)
+    fn verifier_options(&self, connues: &[&str]) -> Result<(), ErreurCli> {
+        for cle in self.options.keys() {
+            if cle != "format" && !connues.contains(&cle.as_str()) {
+                return Err(ErreurCli::Usage(format!("option inconnue --{}", cle)));
+            }
+        }
+        Ok(())
+    }
+
+    // Option à valeur dans une liste fermée (--nature verbe) ; None si absente
+    fn choix<T>(
+        &self,
+        cle: &str,
+        depuis_texte: fn(&str) -> Option<T>,
+        valeurs: &str,
+    ) -> Result<Option<T>, ErreurCli> {
+        let Some(texte) = self.optionnelle(cle) else {
+            return Ok(None);
+        };
+        depuis_texte(texte)
+            .map(Some)
+            .ok_or_else(|| ErreurCli::Usage(format!("--{} '{}' inconnu ({})", cle, texte, valeurs)))
+    }
+
+    // Propriétés d'un schème : --forme --nature --voix --aspect --nombre --genre
+    fn proprietes(&self) -> Result<Proprietes, ErreurCli> {
+        let forme = self.choix("forme", forme_et_systeme, "I à XV, 1 à 15 ou QI à QIV")?;
+        Ok(Proprietes {
+            forme: forme.map(|(f, _)| f),
+            quadrilitere: forme.is_some_and(|(_, q)| q),
+            nature: self.choix(
+                "nature",
+                Nature::depuis_texte,
+                "nom, adjectif, verbe, masdar, participe",
+            )?,
+            voix: self.choix("voix", Voix::depuis_texte, "actif, passif")?,
+            aspect: self.choix(
+                "aspect",
+                Aspect::depuis_texte,
+                "accompli, inaccompli, imperatif",
+            )?,
+            nombre: self.choix("nombre", Nombre::depuis_texte, "singulier, duel, pluriel")?,
+            genre: self.choix("genre", Genre::depuis_texte, "masculin, feminin")?,
+        })
+    }
+
+    // Option numérique (--top 5), `defaut` si absente
+    fn nombre(&self, cle: &str, defaut: usize) -> Result<usize, ErreurCli> {
+        match self.optionnelle(cle) {
+            Some(n) => n
+                .parse()
+                .map_err(|_| ErreurCli::Usage(format!("--{} '{}' : nombre attendu", cle, n))),
+            None => Ok(defaut),
+        }
+    }
+
+    fn categorie(&self) -> Result<Option<Categorie>, ErreurCli> {
+        self.choix("categorie", Categorie::depuis_texte, "voir aide")
+    }
+
+    fn racine(&self) -> Result<Racine, ErreurCli> {
+        Ok(Racine::depuis_texte(self.requise("racine")?)?)
+    }
+
+    fn format(&self) -> Result<Format, ErreurCli> {
+        match self.optionnelle("format").unwrap_or("text") {
+            "text" => Ok(Format::Texte),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            autre => Err(ErreurCli::Usage(format!(
+                "format '{}' inconnu (text, json ou tsv)",
+                autre
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// SORTIE
+// ============================================================================
+
+// Résultat d'une commande : un tableau de lignes, affiché selon le format
+//   text → valeurs séparées par des espaces, sans en-tête
+//   tsv  → en-tête puis valeurs séparées par des tabulations
+//   json → tableau d'objets { colonne: valeur }
+// Un pied de page (valeurs de tout le résultat, pas d'une ligne) s'écrit en
+// dernière ligne en text et tsv ; en json, les lignes passent sous sa clé :
+//   { "total": 120, "decalage": 0, "limite": 50, "racines": [...] }
+struct Tableau {
+    colonnes: Vec<&'static str>,
+    lignes: Vec<Vec<Value>>,
+    pied: Option<Pied>,
+}
+
+struct Pied {
+    texte: String,
+    cle_lignes: &'static str,
+    champs: Map<String, Value>,
+}
+
+impl Tableau {
+    fn new(colonnes: &[&'static str]) -> Self {
+        Tableau {
+            colonnes: colonnes.to_vec(),
+            lignes: Vec::new(),
+            pied: None,
+        }
+    }
+
+    fn avec_pied(
+        mut self,
+        texte: String,
+        cle_lignes: &'static str,
+        champs: &[(&str, Value)],
+    ) -> Self {
+        self.pied = Some(Pied {
+            texte,
+            cle_lignes,
+            champs: champs
+                .iter()
+                .map(|(cle, valeur)| (cle.to_string(), valeur.clone()))
+                .collect(),
+        });
+        self
+    }
+
+    fn ajouter(&mut self, ligne: Vec<Value>) {
+        self.lignes.push(ligne);
+    }
+
+    fn afficher(&self, format: Format) {
+        // Texte et TSV : une liste d'objets s'écrit "و:conjonction,ال:article"
+        // (valeurs de chaque objet dans l'ordre des clés)
+        fn cellule(v: &Value) -> String {
+            match v {
+                Value::String(s) => s.clone(),
+                Value::Null => String::new(),
+                Value::Array(valeurs) => valeurs.iter().map(cellule).collect::<Vec<_>>().join(","),
+                Value::Object(objet) => objet.values().map(cellule).collect::<Vec<_>>().join(":"),
+                autre => autre.to_string(),
+            }
+        }
+
+        let mut sortie = String::new();
+        match format {
+            // Une cellule vide s'écrit "-" : toutes les lignes ont le même
+            // nombre de colonnes
+            Format::Texte => {
+                for ligne in &self.lignes {
+                    let valeurs: Vec<String> = ligne
+                        .iter()
+                        .map(|v| match cellule(v) {
+                            texte if texte.is_empty() => "-".to_string(),
+                            texte => texte,
+                        })
+                        .collect();
+                    sortie += &valeurs.join(" ");
+                    sortie.push('\n');
+                }
+                if let Some(pied) = &self.pied {
+                    sortie += &pied.texte;
+                    sortie.push('\n');
+                }
+            }
+            Format::Tsv => {
+                sortie += &self.colonnes.join("\t");
+                sortie.push('\n');
+                for ligne in &self.lignes {
+                    let valeurs: Vec<String> = ligne.iter().map(cellule).collect();
+                    sortie += &valeurs.join("\t");
+                    sortie.push('\n');
+                }
+                if let Some(pied) = &self.pied {
+                    sortie += &pied.texte;
+                    sortie.push('\n');
+                }
+            }
+            Format::Json => {
+                let objets: Vec<Value> = self
+                    .lignes
+                    .iter()
+                    .map(|ligne| {
+                        let mut objet = Map::new();
+                        for (colonne, valeur) in self.colonnes.iter().zip(ligne) {
+                            objet.insert(colonne.to_string(), valeur.clone());
+                        }
+                        Value::Object(objet)
+                    })
+                    .collect();
+                let json = match &self.pied {
+                    Some(pied) => {
+                        let mut objet = pied.champs.clone();
+                        objet.insert(pied.cle_lignes.to_string(), Value::Array(objets));
+                        Value::Object(objet)
+                    }
+                    None => Value::Array(objets),
+                };
+                sortie += &json.to_string();
+                sortie.push('\n');
+            }
+        }
+
+        // Pas de println! : un tube fermé (`| head`) ne doit pas faire paniquer
+        let _ = io::stdout().write_all(sortie.as_bytes());
+    }
+}
+
+// Une commande produit un tableau et indique si le résultat est positif
+type Resultat = Result<(Tableau, bool), ErreurCli>;
+
+// ============================================================================
+// COMMANDES
+// ============================================================================
+
+fn generer(args: &Arguments, table: &SchemeTable) -> Resultat {
+    args.verifier_options(&["racine", "scheme"])?;
+    let racine = args.racine()?;
+    let nom = args.requise("scheme")?;
+    let mot = generer_par_nom(racine, nom, table)?;
+    // Identifiant du schème retenu : le nom donné peut être un modèle
+    let id = table.trouver(nom)?.id.clone();
+
+    let nu = sans_diacritiques(&mot);
+
+    let mut tableau = Tableau::new(&["racine", "scheme", "mot", "nu"]);
+    tableau.ajouter(vec![
+        racine.to_string().into(),
+        id.into(),
+        mot.into(),
+        nu.into(),
+    ]);
+    Ok((tableau, true))
+}
+
+fn valider(args: &Arguments, table: &SchemeTable) -> Resultat {
+    args.verifier_options(&["mot", "racine"])?;
+    let mot = args.requise("mot")?;
+    let racine = args.racine()?;
+    // Le mot peut porter des clitiques (بكاتبه) : chaque base est validée
+    let resultats = valider_mot_segmente(mot, racine, table);
+    let valide = !resultats.is_empty();
+    let ambigu = resultats
+        .iter()
+        .map(|r| r.validation.lectures.len())
+        .sum::<usize>()
+        > 1;
+
+    // Une ligne par lecture, la découpe la moins segmentée puis la lecture la
+    // plus proche de la saisie en premier
+    let mut tableau = Tableau::new(&[
+        "mot",
+        "racine",
+        "valide",
+        "ambigu",
+        "segmentation",
+        "scheme",
+        "forme",
+        "categorie",
+        "flexion",
+        "conjugaison",
+    ]);
+    if !valide {
+        tableau.ajouter(vec![
+            mot.into(),
+            racine.to_string().into(),
+            false.into(),
+            false.into(),
+            Value::Null,
+            Value::Null,
+            Value::Null,
+            Value::Null,
+            Value::Null,
+            Value::Null,
+        ]);
+    }
+    for resultat in resultats {
+        let segmentation = resultat.segmentation.etiquette();
+        for lecture in resultat.validation.lectures {
+            tableau.ajouter(vec![
+                mot.into(),
+                racine.to_string().into(),
+                true.into(),
+                ambigu.into(),
+                segmentation.clone().into(),
+                lecture.schema.into(),
+                lecture.forme.into(),
+                lecture.categorie.code().into(),
+                lecture.flexion.map(|f| f.etiquette()).into(),
+                lecture.conjugaison.map(|c| c.etiquette()).into(),
+            ]);
+        }
+    }
+    Ok((tableau, valide))
+}
+
+fn famille(args: &Arguments, table: &SchemeTable) -> Resultat {
+    args.verifier_options(&["racine"])?;
+    let racine = args.racine()?;
+
+    let mut tableau = Tableau::new(&["scheme", "mot", "nu", "categorie", "pluriels"]);
+    for membre in generer_famille(racine, table) {
+        let nu = sans_diacritiques(&membre.mot);
+        // Pluriels brisés d'un singulier, séparés par des virgules arabes
+        let pluriels = if membre.pluriels.is_empty() {
+            Value::Null
+        } else {
+            let mots: Vec<&str> = membre.pluriels.iter().map(|p| p.mot.as_str()).collect();
+            mots.join("، ").into()
+        };
+        tableau.ajouter(vec![
+            membre.schema.into(),
+            membre.mot.into(),
+            nu.into(),
+            membre.categorie.code().into(),
+            pluriels,
+        ]);
+    }
+    Ok((tableau, true))
+}
+
+// Une ligne par personne et par temps
+fn conjugaison(args: &Arguments, table: &SchemeTable) -> Resultat {
+    args.verifier_options(&["racine", "forme", "voyelles"])?;
+    let racine = args.racine()?;
+    let forme = forme_depuis_texte(args.requise("forme")?, table)?;
+    let voyelles = match args.optionnelle("voyelles") {
+        Some(texte) => Some(VoyellesI::depuis_texte(texte)?),
+        None => None,
+    };
+    let conjugaison = conjuguer(racine, forme, voyelles)?;
+
+    let mut tableau = Tableau::new(&["forme", "temps", "personne", "pronom", "mot", "nu"]);
+    for temps in &conjugaison.temps {
+        for f in &temps.formes {
+            tableau.ajouter(vec![
+                conjugaison.forme.clone().into(),
+                temps.temps.clone().into(),
+                f.personne.clone().into(),
+                f.pronom.clone().into(),
+                f.mot.clone().into(),
+                sans_diacritiques(&f.mot).into(),
+            ]);
+        }
+    }
+    Ok((tableau, true))
+}
+
+fn analyser(args: &Arguments, arbre: &Tree, table: &SchemeTable) -> Resultat {
+    args.verifier_options(&["mot", "racines"])?;
+    let mot = args.requise("mot")?;
+
+    let mut arbre_fichier = Tree::new();
+    let arbre = racines_connues(args, arbre, &mut arbre_fichier)?;
+
+    let hypotheses = analyser_mot_segmente(mot, table, arbre);
+    let mut tableau = Tableau::new(&[
+        "segmentation",
+        "racine",
+        "scheme",
+        "flexion",
+        "conjugaison",
+        "classe",
+        "dans_arbre",
+    ]);
+    for a in &hypotheses {
+        let h = &a.hypothese;
+        tableau.ajouter(vec![
+            a.segmentation.etiquette().into(),
+            h.racine.to_string().into(),
+            h.scheme.clone().into(),
+            h.flexion.map(|f| f.etiquette()).into(),
+            h.conjugaison.as_ref().map(|c| c.etiquette()).into(),
+            classifier(&h.racine).nom_arabe().into(),
+            h.dans_arbre.into(),
+        ]);
+    }
+    Ok((tableau, !hypotheses.is_empty()))
+}
+
+// Les racines connues permettent de classer les hypothèses (dans_arbre) :
+// celles du lexique, plus celles du fichier --racines (copiées dans `fichier`)
+fn racines_connues<'a>(
+    args: &Arguments,
+    arbre: &'a Tree,
+    fichier: &'a mut Tree,
+) -> Result<&'a Tree, ErreurCli> {
+    let Some(chemin) = args.optionnelle("racines") else {
+        return Ok(arbre);
+    };
+    let mut racines = Vec::new();
+    arbre.collecter_racines(&mut racines);
+    for (racine, _, _) in racines {
+        fichier.insert(racine);
+    }
+    fichier.charger_depuis_fichier(chemin)?;
+    Ok(fichier)
+}
+
+// Analyse mot à mot d'un texte (fichier, ou entrée standard sans fichier / avec -)
+//   --vue jetons       → une ligne par jeton et par analyse retenue (défaut)
+//   --vue statistiques → une ligne par compteur, racine ou schème fréquent
+// Les jetons reconnus sont comptés dans les fréquences des racines du lexique
+fn texte(args: &Arguments, arbre: &mut Tree, table: &SchemeTable) -> Resultat {
+    args.verifier_options(&["racines", "vue", "top"])?;
+    let top = args.nombre("top", TOP_FREQUENCES)?;
+    let vue = |v: &str| match v {
+        "jetons" => Some(false),
+        "statistiques" => Some(true),
+        _ => None,
+    };
+    let statistiques = args
+        .choix("vue", vue, "jetons ou statistiques")?
+        .unwrap_or(false);
+
+    let contenu = match args.positionnels.get(1).map(|s| s.as_str()) {
+        None | Some("-") => {
+            let mut contenu = String::new();
+            io::stdin()
+                .read_to_string(&mut contenu)
+                .map_err(|e| MorphoError::Fichier {
+                    chemin: "<entrée standard>".to_string(),
+                    message: e.to_string(),
+                })?;
+            contenu
+        }
+        Some(chemin) => std::fs::read_to_string(chemin).map_err(|e| MorphoError::Fichier {
+            chemin: chemin.to_string(),
+            message: e.to_string(),
+        })?,
+    };
+
+    let mut arbre_fichier = Tree::new();
+    let connues = racines_connues(args, arbre, &mut arbre_fichier)?;
+    let analyse = analyser_texte(&contenu, table, connues, top);
+    compter_occurrences(&analyse, table, arbre);
+    let s = &analyse.statistiques;
+    let positif = s.reconnus + s.hors_lexique > 0;
+
+    if statistiques {
+        let mut tableau = Tableau::new(&["statistique", "valeur", "nombre"]);
+        for (nom, nombre) in [
+            ("jetons", s.jetons),
+            ("reconnus", s.reconnus),
+            ("hors_lexique", s.hors_lexique),
+            ("inconnus", s.inconnus),
+        ] {
+            tableau.ajouter(vec![nom.into(), Value::Null, nombre.into()]);
+        }
+        tableau.ajouter(vec!["couverture".into(), Value::Null, s.couverture.into()]);
+        for (nom, frequences) in [("racine", &s.racines), ("scheme", &s.schemes)] {
+            for f in frequences {
+                tableau.ajouter(vec![
+                    nom.into(),
+                    f.valeur.clone().into(),
+                    f.occurrences.into(),
+                ]);
+            }
+        }
+        return Ok((tableau, positif));
+    }
+
+    // Un jeton inconnu a une seule ligne, sans analyse
+    let mut tableau = Tableau::new(&[
+        "position",
+        "jeton",
+        "forme",
+        "statut",
+        "segmentation",
+        "racine",
+        "scheme",
+        "flexion",
+        "conjugaison",
+    ]);
+    for jeton in &analyse.jetons {
+        let debut = || -> Vec<Value> {
+            vec![
+                jeton.position.into(),
+                jeton.texte.clone().into(),
+                jeton.forme.clone().into(),
+                jeton.statut.code().into(),
+            ]
+        };
+        if jeton.candidats.is_empty() {
+            let mut ligne = debut();
+            ligne.extend([
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+            ]);
+            tableau.ajouter(ligne);
+        }
+        for c in &jeton.candidats {
+            let mut ligne = debut();
+            ligne.extend([
+                c.segmentation.etiquette().into(),
+                c.racine.clone().into(),
+                c.scheme.clone().into(),
+                c.flexion.map(|f| f.etiquette()).into(),
+                c.conjugaison.as_ref().map(|c| c.etiquette()).into(),
+            ]);
+            tableau.ajouter(ligne);
+        }
+    }
+    Ok((tableau, positif))
+}
+
+// Racines ou dérivés stockés les plus fréquents dans les textes analysés
+fn frequences(args: &Arguments, arbre: &Tree) -> Resultat {
+    args.verifier_options(&["vue", "top"])?;
+    let top = args.nombre("top", TOP_FREQUENCES)?;
+    let vue = |v: &str| match v {
+        "racines" => Some(false),
+        "derives" => Some(true),
+        _ => None,
+    };
+
+    if args
+        .choix("vue", vue, "racines ou derives")?
+        .unwrap_or(false)
+    {
+        let derives = arbre.derives_frequents(top);
+        let mut tableau = Tableau::new(&["racine", "mot", "scheme", "flexion", "occurrences"]);
+        for (racine, d) in &derives {
+            tableau.ajouter(vec![
+                racine.to_string().into(),
+                d.mot.clone().into(),
+                d.schema.clone().into(),
+                d.flexion.map(|f| f.etiquette()).into(),
+                d.occurrences.into(),
+            ]);
+        }
+        return Ok((tableau, !derives.is_empty()));
+    }
+
+    let racines = arbre.racines_frequentes(top);
+    let mut tableau = Tableau::new(&["racine", "frequence", "derives"]);
+    for (racine, frequence, derives) in &racines {
+        tableau.ajouter(vec![
+            racine.to_string().into(),
+            (*frequence).into(),
+            derives.len().into(),
+        ]);
+    }
+    Ok((tableau, !racines.is_empty()))
+}
+
+// Toutes les découpes plausibles d'un mot en clitiques et base
+fn segmentation(args: &Arguments) -> Resultat {
+    args.verifier_options(&["mot"])?;
+    let mot = args.requise("mot")?;
+
+    let mut tableau = Tableau::new(&["segmentation", "proclitiques", "base", "enclitiques"]);
+    for s in segmenter(mot) {
+        // Objets { forme, role } en JSON ; "و:conjonction,ال:article" en texte
+        let clitiques = |clitiques: &[Clitique]| -> Value {
+            if clitiques.is_empty() {
+                return Value::Null;
+            }
+            serde_json::to_value(clitiques).unwrap_or(Value::Null)
+        };
+        tableau.ajouter(vec![
+            s.etiquette().into(),
+            clitiques(&s.proclitiques),
+            s.base.clone().into(),
+            clitiques(&s.enclitiques),
+        ]);
+    }
+    Ok((tableau, true))
+}
+
+// Racines de l'arbre par intervalle, préfixe ou motif, une page à la fois
+// `rang` : position dans tous les résultats ; le pied de page donne le total
+// et la pagination
+fn racines(args: &Arguments, arbre: &Tree) -> Resultat {
+    args.verifier_options(&["debut", "fin", "prefixe", "motif", "decalage", "limite"])?;
+    let pagination = Pagination {
+        decalage: args.nombre("decalage", 0)?,
+        limite: args.nombre("limite", LIMITE_PAGE)?,
+    };
+    let (debut, fin) = (args.optionnelle("debut"), args.optionnelle("fin"));
+    let prefixe = args.optionnelle("prefixe");
+    let motif = args.optionnelle("motif");
+
+    let requetes = [debut.or(fin), prefixe, motif];
+    if requetes.iter().filter(|r| r.is_some()).count() > 1 {
+        return Err(ErreurCli::Usage(
+            "--debut/--fin, --prefixe et --motif sont exclusifs".into(),
+        ));
+    }
+    let page = match (prefixe, motif) {
+        (Some(prefixe), _) => arbre.racines_prefixe(prefixe, pagination)?,
+        (_, Some(motif)) => arbre.racines_motif(motif, pagination)?,
+        _ => arbre.racines_entre(debut, fin, pagination)?,
+    };
+
+    let mut tableau = Tableau::new(&["rang", "racine", "classe", "derives", "frequence"])
+        .avec_pied(
+            format!(
+                "total: {} (décalage {}, limite {})",
+                page.total, pagination.decalage, pagination.limite
+            ),
+            "racines",
+            &[
+                ("total", page.total.into()),
+                ("decalage", pagination.decalage.into()),
+                ("limite", pagination.limite.into()),
+            ],
+        );
+    for (i, noeud) in page.racines.iter().enumerate() {
+        tableau.ajouter(vec![
+            (pagination.decalage + i + 1).into(),
+            noeud.racine.to_string().into(),
+            classifier(&noeud.racine).nom_arabe().into(),
+            noeud.derives.len().into(),
+            noeud.frequence.into(),
+        ]);
+    }
+    Ok((tableau, !page.racines.is_empty()))
+}
+
+// Ajoute les racines du fichier à l'arbre et affiche tout l'arbre
+fn charger(args: &Arguments, arbre: &mut Tree) -> Resultat {
+    args.verifier_options(&["fichier"])?;
+    let fichier = match (args.optionnelle("fichier"), args.positionnels.get(1)) {
+        (Some(f), _) => f,
+        (None, Some(f)) => f.as_str(),
+        (None, None) => return Err(ErreurCli::Usage("fichier de racines manquant".into())),
+    };
+
+    arbre.charger_depuis_fichier(fichier)?;
+
+    let mut racines = Vec::new();
+    arbre.collecter_racines(&mut racines);
+    let mut tableau = Tableau::new(&["racine", "classe"]);
+    for (racine, _, _) in racines {
+        tableau.ajouter(vec![
+            racine.to_string().into(),
+            classifier(&racine).nom_arabe().into(),
+        ]);
+    }
+    Ok((tableau, true))
+}
+
+// Fusionner un export JSON dans l'état courant et lister ce qui a changé
+fn importer(args: &Arguments, arbre: &mut Tree, table: &mut SchemeTable) -> Resultat {
+    args.verifier_options(&["politique"])?;
+    let Some(fichier) = args.positionnels.get(1) else {
+        return Err(ErreurCli::Usage("fichier à importer manquant".into()));
+    };
+    let texte = args.optionnelle("politique").unwrap_or("local");
+    let politique = PolitiqueConflit::depuis_texte(texte).ok_or_else(|| {
+        ErreurCli::Usage(format!(
+            "politique '{}' inconnue (local, importe ou echec)",
+            texte
+        ))
+    })?;
+
+    let rapport = Lexique::lire_fichier(fichier)?.fusionner(arbre, table, politique)?;
+
+    let mut tableau = Tableau::new(&["operation", "element", "detail"]);
+    for racine in rapport.racines_ajoutees {
+        tableau.ajouter(vec!["racine_ajoutee".into(), racine.into(), Value::Null]);
+    }
+    for d in rapport.derives_ajoutes {
+        tableau.ajouter(vec!["derive_ajoute".into(), d.mot.into(), d.racine.into()]);
+    }
+    for d in rapport.derives_ignores {
+        tableau.ajouter(vec!["derive_ignore".into(), d.mot.into(), d.racine.into()]);
+    }
+    for cle in rapport.schemes_ajoutes {
+        tableau.ajouter(vec!["scheme_ajoute".into(), cle.into(), Value::Null]);
+    }
+    for cle in rapport.schemes_ignores {
+        tableau.ajouter(vec!["scheme_ignore".into(), cle.into(), Value::Null]);
+    }
+    for c in rapport.conflits {
+        let retenu = if c.garde_importe { "importe" } else { "local" };
+        tableau.ajouter(vec!["conflit".into(), c.cle.into(), retenu.into()]);
+    }
+    Ok((tableau, true))
+}
+
+fn ligne_scheme(cle: &str, scheme: &Scheme) -> Vec<Value> {
+    let p = &scheme.proprietes;
+    vec![
+        cle.into(),
+        scheme.nom.clone().into(),
+        scheme.pattern.clone().into(),
+        scheme.nombre_radicaux().into(),
+        scheme.description.clone().into(),
+        scheme.categorie.code().into(),
+        if scheme.pluriels.is_empty() {
+            Value::Null
+        } else {
+            scheme.pluriels.join(",").into()
+        },
+        p.nom_forme().into(),
+        p.nature.map(|n| n.code()).into(),
+        p.voix.map(|v| v.code()).into(),
+        p.aspect.map(|a| a.code()).into(),
+        p.nombre.map(|n| n.code()).into(),
+        p.genre.map(|g| g.code()).into(),
+    ]
+}
+
+const COLONNES_SCHEME: [&str; 13] = [
+    "cle",
+    "nom",
+    "pattern",
+    "radicaux",
+    "description",
+    "categorie",
+    "pluriels",
+    "forme",
+    "nature",
+    "voix",
+    "aspect",
+    "nombre",
+    "genre",
+];
+
+// Options de propriétés, communes à `schemes list` (filtres) et `schemes add`
+const OPTIONS_PROPRIETES: [&str; 6] = ["forme", "nature", "voix", "aspect", "nombre", "genre"];
+
+fn schemes(args: &Arguments, table: &mut SchemeTable) -> Resultat {
+    let mut tableau = Tableau::new(&COLONNES_SCHEME);
+
+    match args.positionnels.get(1).map(|s| s.as_str()) {
+        Some("list") => {
+            let filtres = ["categorie", "radicaux", "modele"];
+            args.verifier_options(&[&OPTIONS_PROPRIETES[..], &filtres].concat())?;
+            let radicaux = match args.optionnelle("radicaux") {
+                None => None,
+                Some(texte) => Some(texte.parse::<usize>().map_err(|_| {
+                    ErreurCli::Usage(format!("--radicaux '{}' invalide (3 ou 4)", texte))
+                })?),
+            };
+            let criteres = Criteres {
+                proprietes: args.proprietes()?,
+                categorie: args.categorie()?,
+                radicaux,
+                modele: args.optionnelle("modele").map(str::to_string),
+            };
+            let entrees = table.filtrer(&criteres);
+            let trouve = !entrees.is_empty();
+            for (cle, scheme) in entrees {
+                tableau.ajouter(ligne_scheme(cle, scheme));
+            }
+            return Ok((tableau, trouve));
+        }
+        Some("add") => {
+            let options = [
+                "id",
+                "nom",
+                "pattern",
+                "description",
+                "categorie",
+                "pluriels",
+            ];
+            args.verifier_options(&[&OPTIONS_PROPRIETES[..], &options].concat())?;
+            let nom = args.requise("nom")?;
+            // Identifiant distinct pour un second schème de même nom (فاعل_III)
+            let id = args.optionnelle("id").unwrap_or(nom);
+            let pattern = args.optionnelle("pattern").unwrap_or("");
+            let description = args.optionnelle("description").unwrap_or("");
+            let categorie = args.categorie()?.unwrap_or_default();
+            // Clés des schèmes de pluriel, séparées par des virgules
+            let pluriels: Vec<&str> = args
+                .optionnelle("pluriels")
+                .unwrap_or("")
+                .split([',', '،'])
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+                .collect();
+            let scheme = Scheme::new(nom, pattern, description)?
+                .avec_categorie(categorie)
+                .avec_pluriels(&pluriels)
+                .avec_proprietes(args.proprietes()?);
+            tableau.ajouter(ligne_scheme(id, &scheme));
+            table.try_insert(id.to_string(), scheme)?;
+        }
+        Some("remove") => {
+            args.verifier_options(&["nom"])?;
+            let nom = args.requise("nom")?;
+            let scheme = table.obtenir(nom)?.clone();
+            table.delete(nom)?;
+            tableau.ajouter(ligne_scheme(nom, &scheme));
+        }
+        Some(autre) => {
+            return Err(ErreurCli::Usage(format!(
+                "sous-commande 'schemes {}' inconnue (list, add, remove)",
+                autre
+            )));
+        }
+        None => {
+            return Err(ErreurCli::Usage(
+                "schemes attend list, add ou remove".into(),
+            ));
+        }
+    }
+    Ok((tableau, true))
+}
+
+// ============================================================================
+// POINT D'ENTRÉE
+// ============================================================================
+
+// Retirer l'option globale --lexique des arguments (commandes et repl)
+pub fn extraire_lexique(args: &mut Vec<String>) -> Result<Option<String>, String> {
+    let mut lexique = None;
+    let mut i = 0;
+    while i < args.len() {
+        if let Some(chemin) = args[i].strip_prefix("--lexique=") {
+            lexique = Some(chemin.to_string());
+            args.remove(i);
+        } else if args[i] == "--lexique" {
+            if i + 1 >= args.len() {
+                return Err("l'option --lexique attend un fichier".to_string());
+            }
+            lexique = Some(args.remove(i + 1));
+            args.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(lexique)
+}
+
+// Lire l'état depuis le lexique (un fichier absent donne un état neuf)
+pub fn charger_lexique(lexique: Option<&str>) -> Result<(Tree, SchemeTable), MorphoError> {
+    let mut arbre = Tree::new();
+    let mut table = init_schemes();
+    if let Some(chemin) = lexique
+        && Path::new(chemin).exists()
+    {
+        Lexique::lire_fichier(chemin)?.appliquer(&mut arbre, &mut table)?;
+    }
+    Ok((arbre, table))
+}
+
+// Exécuter une commande et retourner le code de sortie du processus
+pub fn executer(args: &[String], lexique: Option<&str>) -> i32 {
+    let (format, resultat) = match Arguments::analyser(args) {
+        Ok(arguments) => match arguments.format() {
+            Ok(format) => (format, lancer(&arguments, lexique)),
+            Err(e) => (Format::Texte, Err(e)),
+        },
+        Err(e) => (Format::Texte, Err(e)),
+    };
+
+    match resultat {
+        Ok((tableau, positif)) => {
+            tableau.afficher(format);
+            if positif { SUCCES } else { NEGATIF }
+        }
+        Err(ErreurCli::Usage(message)) => {
+            eprintln!("Erreur : {}\n\n{}", message, AIDE);
+            USAGE
+        }
+        Err(ErreurCli::Moteur(e)) => {
+            if format == Format::Json {
+                eprintln!(
+                    "{}",
+                    serde_json::json!({ "code": e.code(), "message": e.to_string() })
+                );
+            } else {
+                eprintln!("Erreur [{}] : {}", e.code(), e);
+            }
+            ERREUR
+        }
+    }
+}
+
+fn lancer(args: &Arguments, lexique: Option<&str>) -> Resultat {
+    let (mut arbre, mut table) = charger_lexique(lexique)?;
+
+    let commande = args.positionnels.first().map(|s| s.as_str());
+    let resultat = match commande {
+        Some("generer") => generer(args, &table),
+        Some("valider") => valider(args, &table),
+        Some("famille") => famille(args, &table),
+        Some("analyser") => analyser(args, &arbre, &table),
+        Some("segmenter") => segmentation(args),
+        Some("texte") => texte(args, &mut arbre, &table),
+        Some("frequences") => frequences(args, &arbre),
+        Some("racines") => racines(args, &arbre),
+        Some("conjuguer") => conjugaison(args, &table),
+        Some("charger") => charger(args, &mut arbre),
+        Some("importer") => importer(args, &mut arbre, &mut table),
+        Some("schemes") => schemes(args, &mut table),
+        Some(commande) => Err(ErreurCli::Usage(format!(
+            "commande '{}' inconnue",
+            commande
+        ))),
+        None => Err(ErreurCli::Usage("commande manquante".into())),
+    }?;
+
+    // Sauvegarder après une commande qui modifie l'état
+    let modifie = match commande {
+        Some("charger") | Some("importer") | Some("texte") => true,
+        Some("schemes") => args.positionnels.get(1).is_some_and(|s| s != "list"),
+        _ => false,
+    };
+    if modifie && let Some(chemin) = lexique {
+        Lexique::depuis(&arbre, &table).ecrire_fichier(chemin)?;
+    }
+
+    Ok(resultat)
+}
@@ -0,0 +1,1001 @@
+// ============================================================================
+// CONJUGAISON DES VERBES (formes I à X, quadrilitères I et II)
+// ============================================================================
+// Pour une racine et une forme, le tableau complet :
+//   accompli actif et passif (الماضي)
+//   inaccompli actif et passif, à l'indicatif (المضارع المرفوع)
+//   impératif (الأمر)
+// aux 13 personnes (هو ... نحن), 5 à l'impératif.
+//
+// Chaque forme est décrite par ses RADICAUX VERBAUX : des modèles ف/ع/ل
+// vocalisés, sans la voyelle du dernier radical (كَتَب, كْتُب). Un mot
+// conjugué = préfixe de personne + radical + désinence, rempli et orthographié
+// par `generer_gabarit` comme les dérivés de la table des schèmes (hamzas,
+// assimilation du ت de la forme VIII).
+//
+// Racines faibles : le radical est transformé avant le remplissage
+//   مضعف : مَدَّ / مَدَدْتُ  radical contracté devant une voyelle (et forme IX)
+//   أجوف : قَالَ / قُلْتُ   voyelle longue devant une voyelle, brève sinon
+//   ناقص : رَمَى / رَمَيْتُ / يَرْمِي  désinences propres (voir plus bas)
+//   مثال : يَعِدُ / عِدْ / اتَّعَدَ  le و tombe à l'inaccompli de la forme I
+// Les irrégularités propres à un verbe (رأى يرى، كُلْ، خُذْ) ne sont pas traitées.
+//
+// En sens inverse, `reconnaitre` retrouve un mot conjugué (يكتبون) dans le
+// tableau : la validation et l'analyse s'en servent pour les verbes qui ne
+// sont la forme d'aucun schème de la table.
+
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::erreur::MorphoError;
+use crate::gabarit::{Gabarit, SHADDA, est_diacritique, sans_diacritiques};
+use crate::hashing::{Scheme, SchemeTable};
+use crate::morpho_analyzer::generer_gabarit;
+use crate::normalisation::{Normalisation, equivalent};
+use crate::phonologie::{ClasseRacine, classifier, est_faible};
+use crate::proprietes::{Aspect, Genre, Nombre, Proprietes, Voix};
+use crate::racine::Racine;
+
+const FATHA: char = '\u{064E}';
+const DAMMA: char = '\u{064F}';
+const KASRA: char = '\u{0650}';
+const SUKUN: char = '\u{0652}';
+
+// ========================================================
+// FORMES VERBALES
+// ========================================================
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Forme {
+    I,
+    II,
+    III,
+    IV,
+    V,
+    VI,
+    VII,
+    VIII,
+    IX,
+    X,
+    // Quadrilitères : فعلل، تفعلل
+    QI,
+    QII,
+}
+
+impl Forme {
+    pub const TOUTES: [Forme; 12] = [
+        Forme::I,
+        Forme::II,
+        Forme::III,
+        Forme::IV,
+        Forme::V,
+        Forme::VI,
+        Forme::VII,
+        Forme::VIII,
+        Forme::IX,
+        Forme::X,
+        Forme::QI,
+        Forme::QII,
+    ];
+
+    // Numéro usuel de la forme
+    pub fn numero(&self) -> &'static str {
+        match self {
+            Forme::I => "I",
+            Forme::II => "II",
+            Forme::III => "III",
+            Forme::IV => "IV",
+            Forme::V => "V",
+            Forme::VI => "VI",
+            Forme::VII => "VII",
+            Forme::VIII => "VIII",
+            Forme::IX => "IX",
+            Forme::X => "X",
+            Forme::QI => "QI",
+            Forme::QII => "QII",
+        }
+    }
+
+    // Accompli actif, 3ème personne du masculin singulier : la forme qui sert
+    // de nom à la forme verbale
+    pub fn modele_vocalise(&self) -> &'static str {
+        match self {
+            Forme::I => "فَعَلَ",
+            Forme::II => "فَعَّلَ",
+            Forme::III => "فَاعَلَ",
+            Forme::IV => "أَفْعَلَ",
+            Forme::V => "تَفَعَّلَ",
+            Forme::VI => "تَفَاعَلَ",
+            Forme::VII => "انْفَعَلَ",
+            Forme::VIII => "افْتَعَلَ",
+            Forme::IX => "افْعَلَّ",
+            Forme::X => "اسْتَفْعَلَ",
+            Forme::QI => "فَعْلَلَ",
+            Forme::QII => "تَفَعْلَلَ",
+        }
+    }
+
+    // Modèle sans voyelles (استفعل)
+    pub fn modele(&self) -> String {
+        sans_diacritiques(self.modele_vocalise())
+    }
+
+    pub fn nombre_radicaux(&self) -> usize {
+        match self {
+            Forme::QI | Forme::QII => 4,
+            _ => 3,
+        }
+    }
+
+    // Reconnaître une forme : numéro romain ou arabe ("X", "10"), ou modèle
+    // avec ou sans voyelles (استفعل، افعل pour أفعل)
+    pub fn depuis_texte(texte: &str) -> Option<Forme> {
+        let texte = texte.trim();
+        let cle = Normalisation::CLE_SCHEME.appliquer(texte);
+        Forme::TOUTES
+            .into_iter()
+            .enumerate()
+            .find(|(i, forme)| {
+                forme.numero().eq_ignore_ascii_case(texte)
+                    || (*i < 10 && texte == (i + 1).to_string())
+                    || Normalisation::CLE_SCHEME.appliquer(forme.modele_vocalise()) == cle
+            })
+            .map(|(_, forme)| forme)
+    }
+}
+
+// Forme désignée par l'utilisateur : schème de la table (identifiant, ou modèle
+// qui ne désigne qu'un schème) dont le gabarit est l'accompli d'une forme
+// (فاعل_III → III), sinon numéro ou modèle
+// (voir `Forme::depuis_texte`)
+pub fn forme_depuis_texte(texte: &str, table: &SchemeTable) -> Result<Forme, MorphoError> {
+    let par_scheme = table.trouver(texte).ok().and_then(forme_du_scheme);
+    par_scheme
+        .or_else(|| Forme::depuis_texte(texte))
+        .ok_or_else(|| MorphoError::FormeInconnue(texte.to_string()))
+}
+
+// Forme verbale dont le schème est l'accompli (فعل → I, فاعل_III → III),
+// None pour un schème qui n'est pas un verbe
+pub fn forme_du_scheme(scheme: &Scheme) -> Option<Forme> {
+    Forme::TOUTES
+        .into_iter()
+        .find(|forme| Gabarit::depuis_nom(forme.modele_vocalise()) == scheme.gabarit)
+}
+
+// Voyelle du 2ème radical de la forme I (باب), à l'accompli et à l'inaccompli :
+//   كَتَبَ يَكْتُبُ = a-u   ضَرَبَ يَضْرِبُ = a-i   فَتَحَ يَفْتَحُ = a-a
+//   عَلِمَ يَعْلَمُ = i-a   حَسِبَ يَحْسِبُ = i-i   كَرُمَ يَكْرُمُ = u-u
+// Elles ne se déduisent pas de la racine : l'utilisateur peut les préciser
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VoyellesI {
+    pub accompli: char,
+    pub inaccompli: char,
+}
+
+impl VoyellesI {
+    // Les six باب, dans l'ordre du tableau ci-dessus
+    pub const TOUTES: [VoyellesI; 6] = [
+        VoyellesI::new(FATHA, DAMMA),
+        VoyellesI::new(FATHA, KASRA),
+        VoyellesI::new(FATHA, FATHA),
+        VoyellesI::new(KASRA, FATHA),
+        VoyellesI::new(KASRA, KASRA),
+        VoyellesI::new(DAMMA, DAMMA),
+    ];
+
+    const fn new(accompli: char, inaccompli: char) -> VoyellesI {
+        VoyellesI {
+            accompli,
+            inaccompli,
+        }
+    }
+
+    // "a-u", "i-a"... (a = fatha, i = kasra, u = damma)
+    pub fn depuis_texte(texte: &str) -> Result<VoyellesI, MorphoError> {
+        let voyelle = |c: char| match c {
+            'a' => Some(FATHA),
+            'i' => Some(KASRA),
+            'u' => Some(DAMMA),
+            _ => None,
+        };
+        let lettres: Vec<char> = texte
+            .trim()
+            .chars()
+            .filter(|c| !matches!(c, '-' | '/' | ' '))
+            .collect();
+        match lettres.as_slice() {
+            [a, b] => match (voyelle(*a), voyelle(*b)) {
+                (Some(accompli), Some(inaccompli)) => Ok(VoyellesI {
+                    accompli,
+                    inaccompli,
+                }),
+                _ => Err(MorphoError::VoyellesInvalides(texte.to_string())),
+            },
+            _ => Err(MorphoError::VoyellesInvalides(texte.to_string())),
+        }
+    }
+
+    // Le باب le plus courant pour la classe de la racine : قَالَ يَقُولُ،
+    // بَاعَ يَبِيعُ، رَمَى يَرْمِي، وَعَدَ يَعِدُ ; a-u sinon
+    pub fn par_defaut(racine: &Racine) -> VoyellesI {
+        let r = racine.lettres();
+        let en_kasra = match classifier(racine) {
+            ClasseRacine::Creuse => r[1] == 'ي',
+            ClasseRacine::Defectueuse | ClasseRacine::Lafif => r[2] != 'و',
+            ClasseRacine::Assimilee => true,
+            _ => false,
+        };
+        VoyellesI {
+            accompli: FATHA,
+            inaccompli: if en_kasra { KASRA } else { DAMMA },
+        }
+    }
+
+    // Écriture "a-u"
+    pub fn code(&self) -> String {
+        let lettre = |v: char| match v {
+            KASRA => 'i',
+            DAMMA => 'u',
+            _ => 'a',
+        };
+        format!("{}-{}", lettre(self.accompli), lettre(self.inaccompli))
+    }
+}
+
+// ========================================================
+// TEMPS ET PERSONNES
+// ========================================================
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Temps {
+    Accompli,
+    AccompliPassif,
+    Inaccompli,
+    InaccompliPassif,
+    Imperatif,
+}
+
+impl Temps {
+    pub const TOUS: [Temps; 5] = [
+        Temps::Accompli,
+        Temps::AccompliPassif,
+        Temps::Inaccompli,
+        Temps::InaccompliPassif,
+        Temps::Imperatif,
+    ];
+
+    // Code stable, utilisé dans les exports JSON
+    pub fn code(&self) -> &'static str {
+        match self {
+            Temps::Accompli => "accompli",
+            Temps::AccompliPassif => "accompli_passif",
+            Temps::Inaccompli => "inaccompli",
+            Temps::InaccompliPassif => "inaccompli_passif",
+            Temps::Imperatif => "imperatif",
+        }
+    }
+
+    pub fn nom_arabe(&self) -> &'static str {
+        match self {
+            Temps::Accompli => "الماضي المبني للمعلوم",
+            Temps::AccompliPassif => "الماضي المبني للمجهول",
+            Temps::Inaccompli => "المضارع المبني للمعلوم",
+            Temps::InaccompliPassif => "المضارع المبني للمجهول",
+            Temps::Imperatif => "الأمر",
+        }
+    }
+}
+
+// Les désinences commencent par la voyelle (ou le sukun) du dernier radical :
+// devant un sukun, les racines مضعف et أجوف gardent leur radical long
+
+// (code, pronom, préfixe de l'inaccompli, désinence de l'accompli, désinence
+// de l'inaccompli)
+const PERSONNES: [(&str, &str, char, &str, &str); 13] = [
+    ("3ms", "هُوَ", 'ي', "َ", "ُ"),
+    ("3fs", "هِيَ", 'ت', "َتْ", "ُ"),
+    ("3md", "هُمَا", 'ي', "َا", "َانِ"),
+    ("3fd", "هُمَا", 'ت', "َتَا", "َانِ"),
+    ("3mp", "هُمْ", 'ي', "ُوا", "ُونَ"),
+    ("3fp", "هُنَّ", 'ي', "ْنَ", "ْنَ"),
+    ("2ms", "أَنْتَ", 'ت', "ْتَ", "ُ"),
+    ("2fs", "أَنْتِ", 'ت', "ْتِ", "ِينَ"),
+    ("2d", "أَنْتُمَا", 'ت', "ْتُمَا", "َانِ"),
+    ("2mp", "أَنْتُمْ", 'ت', "ْتُمْ", "ُونَ"),
+    ("2fp", "أَنْتُنَّ", 'ت', "ْتُنَّ", "ْنَ"),
+    ("1s", "أَنَا", 'أ', "ْتُ", "ُ"),
+    ("1p", "نَحْنُ", 'ن', "ْنَا", "ُ"),
+];
+
+// Impératif : (indice dans PERSONNES, désinence)
+const IMPERATIF: [(usize, &str); 5] = [(6, "ْ"), (7, "ِي"), (8, "َا"), (9, "ُوا"), (10, "ْنَ")];
+
+// ناقص : la voyelle du 2ème radical et le 3ème radical sont remplacés par ces
+// désinences (même ordre que PERSONNES ou IMPERATIF), choisies d'après la
+// voyelle du 2ème radical dans le radical verbal
+
+// رَمَى، أَعْطَى، اسْتَرْضَى
+const ACCOMPLI_YA: [&str; 13] = [
+    "َى",
+    "َتْ",
+    "َيَا",
+    "َتَا",
+    "َوْا",
+    "َيْنَ",
+    "َيْتَ",
+    "َيْتِ",
+    "َيْتُمَا",
+    "َيْتُمْ",
+    "َيْتُنَّ",
+    "َيْتُ",
+    "َيْنَا",
+];
+// دَعَا (forme I, 3ème radical و)
+const ACCOMPLI_WAW: [&str; 13] = [
+    "َا",
+    "َتْ",
+    "َوَا",
+    "َتَا",
+    "َوْا",
+    "َوْنَ",
+    "َوْتَ",
+    "َوْتِ",
+    "َوْتُمَا",
+    "َوْتُمْ",
+    "َوْتُنَّ",
+    "َوْتُ",
+    "َوْنَا",
+];
+// لَقِيَ، passif رُمِيَ
+const ACCOMPLI_KASRA: [&str; 13] = [
+    "ِيَ",
+    "ِيَتْ",
+    "ِيَا",
+    "ِيَتَا",
+    "ُوا",
+    "ِينَ",
+    "ِيتَ",
+    "ِيتِ",
+    "ِيتُمَا",
+    "ِيتُمْ",
+    "ِيتُنَّ",
+    "ِيتُ",
+    "ِينَا",
+];
+// يَرْمِي، يُعْطِي
+const INACCOMPLI_KASRA: [&str; 13] = [
+    "ِي",
+    "ِي",
+    "ِيَانِ",
+    "ِيَانِ",
+    "ُونَ",
+    "ِينَ",
+    "ِي",
+    "ِينَ",
+    "ِيَانِ",
+    "ُونَ",
+    "ِينَ",
+    "ِي",
+    "ِي",
+];
+// يَدْعُو
+const INACCOMPLI_DAMMA: [&str; 13] = [
+    "ُو",
+    "ُو",
+    "ُوَانِ",
+    "ُوَانِ",
+    "ُونَ",
+    "ُونَ",
+    "ُو",
+    "ِينَ",
+    "ُوَانِ",
+    "ُونَ",
+    "ُونَ",
+    "ُو",
+    "ُو",
+];
+// يَلْقَى، يَتَلَقَّى، passif يُرْمَى
+const INACCOMPLI_FATHA: [&str; 13] = [
+    "َى",
+    "َى",
+    "َيَانِ",
+    "َيَانِ",
+    "َوْنَ",
+    "َيْنَ",
+    "َى",
+    "َيْنَ",
+    "َيَانِ",
+    "َوْنَ",
+    "َيْنَ",
+    "َى",
+    "َى",
+];
+const IMPERATIF_KASRA: [&str; 5] = ["ِ", "ِي", "ِيَا", "ُوا", "ِينَ"];
+const IMPERATIF_DAMMA: [&str; 5] = ["ُ", "ِي", "ُوَا", "ُوا", "ُونَ"];
+const IMPERATIF_FATHA: [&str; 5] = ["َ", "َيْ", "َيَا", "َوْا", "َيْنَ"];
+
+// ========================================================
+// RÉSULTAT
+// ========================================================
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FormeConjuguee {
+    // "3ms", "2fp"... (personne, genre, nombre)
+    pub personne: String,
+    pub pronom: String,
+    pub mot: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TempsConjugue {
+    pub temps: String,
+    pub nom: String,
+    pub formes: Vec<FormeConjuguee>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TableConjugaison {
+    pub racine: String,
+    pub forme: String,
+    pub modele: String,
+    pub classe: String,
+    // باب de la forme I ("a-u"), absent pour les autres formes
+    pub voyelles: Option<String>,
+    pub temps: Vec<TempsConjugue>,
+}
+
+// ========================================================
+// RADICAUX VERBAUX
+// ========================================================
+
+#[derive(Clone, Copy, PartialEq)]
+enum PrefixeImperatif {
+    Aucun,       // فَعِّلْ، تَعَلَّمْ
+    Wasl,        // اِنْفَعِلْ، اِسْتَفْعِلْ
+    WaslSiSukun, // forme I : اُكْتُبْ, mais قُلْ، عِدْ
+    Hamza,       // forme IV : أَكْرِمْ، أَقِمْ
+}
+
+// Radicaux d'une forme, sans la voyelle du dernier radical
+struct Radicaux {
+    accompli: String,
+    accompli_passif: Option<String>,
+    // Sans le préfixe de personne
+    inaccompli: String,
+    inaccompli_passif: Option<String>,
+    // Préfixe de l'inaccompli actif en damma (يُفَعِّلُ) ou en fatha ;
+    // toujours en damma au passif
+    prefixe_damma: bool,
+    imperatif: PrefixeImperatif,
+}
+
+fn radicaux(forme: Forme, voyelles: VoyellesI) -> Radicaux {
+    let radicaux = |accompli: &str,
+                    accompli_passif: Option<&str>,
+                    inaccompli: &str,
+                    inaccompli_passif: Option<&str>,
+                    prefixe_damma: bool,
+                    imperatif: PrefixeImperatif| Radicaux {
+        accompli: accompli.to_string(),
+        accompli_passif: accompli_passif.map(str::to_string),
+        inaccompli: inaccompli.to_string(),
+        inaccompli_passif: inaccompli_passif.map(str::to_string),
+        prefixe_damma,
+        imperatif,
+    };
+    use PrefixeImperatif::*;
+
+    match forme {
+        Forme::I => radicaux(
+            &format!("ف{}ع{}ل", FATHA, voyelles.accompli),
+            Some("فُعِل"),
+            &format!("ف{}ع{}ل", SUKUN, voyelles.inaccompli),
+            Some("فْعَل"),
+            false,
+            WaslSiSukun,
+        ),
+        Forme::II => radicaux("فَعَّل", Some("فُعِّل"), "فَعِّل", Some("فَعَّل"), true, Aucun),
+        Forme::III => radicaux("فَاعَل", Some("فُوعِل"), "فَاعِل", Some("فَاعَل"), true, Aucun),
+        Forme::IV => radicaux("أَفْعَل", Some("أُفْعِل"), "فْعِل", Some("فْعَل"), true, Hamza),
+        Forme::V => radicaux("تَفَعَّل", Some("تُفُعِّل"), "تَفَعَّل", Some("تَفَعَّل"), false, Aucun),
+        Forme::VI => radicaux("تَفَاعَل", Some("تُفُوعِل"), "تَفَاعَل", Some("تَفَاعَل"), false, Aucun),
+        // Formes VII et IX : intransitives, sans passif
+        Forme::VII => radicaux("انْفَعَل", None, "نْفَعِل", None, false, Wasl),
+        Forme::VIII => radicaux("افْتَعَل", Some("افْتُعِل"), "فْتَعِل", Some("فْتَعَل"), false, Wasl),
+        // Le 3ème radical est redoublé : احْمَرَرْتُ, contracté en احْمَرَّ
+        Forme::IX => radicaux("افْعَلَل", None, "فْعَلِل", None, false, Wasl),
+        Forme::X => radicaux(
+            "اسْتَفْعَل",
+            Some("اسْتُفْعِل"),
+            "سْتَفْعِل",
+            Some("سْتَفْعَل"),
+            false,
+            Wasl,
+        ),
+        Forme::QI => radicaux("فَعْلَل", Some("فُعْلِل"), "فَعْلِل", Some("فَعْلَل"), true, Aucun),
+        Forme::QII => radicaux("تَفَعْلَل", Some("تُفُعْلِل"), "تَفَعْلَل", Some("تَفَعْلَل"), false, Aucun),
+    }
+}
+
+// ========================================================
+// TRANSFORMATIONS DU RADICAL (racines faibles)
+// ========================================================
+
+// ناقص : retirer le 3ème radical et la voyelle du 2ème (رَمَل → رَم, fatha)
+fn tronquer(radical: &str) -> (String, char) {
+    let mut lettres: Vec<char> = radical.chars().collect();
+    if let Some(p) = lettres.iter().rposition(|c| *c == 'ل') {
+        lettres.truncate(p);
+    }
+    let voyelle = match lettres.last() {
+        Some(&v) if est_diacritique(v) => {
+            lettres.pop();
+            v
+        }
+        _ => FATHA,
+    };
+    (lettres.into_iter().collect(), voyelle)
+}
+
+// أجوف : le 2ème radical disparaît ; sa voyelle passe à la lettre précédente
+// quand celle-ci n'en a pas, et s'allonge devant une voyelle
+// (قْوُل → قُول / قُل, passif قُوِل → قِيل / قِل)
+// `forcee` impose la voyelle brève (accompli de la forme I : قُلْتُ، بِعْتُ)
+fn radical_creux(radical: &str, longue: bool, forcee: Option<char>) -> String {
+    let lettres: Vec<char> = radical.chars().collect();
+    let Some(p) = lettres.iter().position(|c| *c == 'ع') else {
+        return radical.to_string();
+    };
+    let (voyelle_ain, fin) = match lettres.get(p + 1) {
+        Some(&v) if est_diacritique(v) => (v, p + 2),
+        _ => (FATHA, p + 1),
+    };
+    let (mut debut, precedente) = match lettres[..p].last() {
+        Some(&v) if est_diacritique(v) => (lettres[..p - 1].to_vec(), Some(v)),
+        _ => (lettres[..p].to_vec(), None),
+    };
+
+    let voyelle = forcee.unwrap_or(match precedente {
+        Some(SUKUN) | None => voyelle_ain,
+        Some(DAMMA) if voyelle_ain == KASRA => KASRA,
+        Some(v) => v,
+    });
+    debut.push(voyelle);
+    if longue {
+        debut.push(match voyelle {
+            DAMMA => 'و',
+            KASRA => 'ي',
+            _ => 'ا',
+        });
+    }
+    debut.extend(&lettres[fin..]);
+    debut.into_iter().collect()
+}
+
+// مضعف et forme IX : les deux derniers radicaux, séparés par une simple
+// voyelle, fusionnent ; la lettre précédente prend cette voyelle si elle porte
+// un sukun (مَدَد → مَدّ, مْدُد → مُدّ, حْمَرَر → حْمَرّ)
+// Un radical où ils ne se touchent pas (مَدَّد) est rendu tel quel
+fn contracter(radical: &str) -> String {
+    let lettres: Vec<char> = radical.chars().collect();
+    let Some(dernier) = lettres.iter().rposition(|c| *c == 'ل') else {
+        return radical.to_string();
+    };
+    if dernier < 2
+        || !matches!(lettres[dernier - 2], 'ع' | 'ل')
+        || !est_diacritique(lettres[dernier - 1])
+    {
+        return radical.to_string();
+    }
+    let voyelle = lettres[dernier - 1];
+
+    let mut debut = lettres[..dernier - 2].to_vec();
+    if let Some(v) = debut.last_mut()
+        && *v == SUKUN
+    {
+        *v = voyelle;
+    }
+    debut.push(lettres[dernier - 2]);
+    debut.push(SHADDA);
+    debut.extend(&lettres[dernier + 1..]);
+    debut.into_iter().collect()
+}
+
+// Une lettre de prolongation ne porte pas de sukun : ni و après damma, ni ي
+// après kasra (يُوْعَدُ → يُوعَدُ)
+fn sans_sukun_de_prolongation(mot: &str) -> String {
+    let mut resultat = String::with_capacity(mot.len());
+    let mut lettre = '\0';
+    let mut voyelle_prec = None;
+    let mut voyelle = None;
+
+    for c in mot.chars() {
+        if est_diacritique(c) {
+            let prolongation = (lettre == 'و' && voyelle_prec == Some(DAMMA))
+                || (lettre == 'ي' && voyelle_prec == Some(KASRA));
+            if c == SUKUN && prolongation {
+                continue;
+            }
+            voyelle = Some(c);
+        } else if c != SHADDA {
+            voyelle_prec = voyelle;
+            voyelle = None;
+            lettre = c;
+        }
+        resultat.push(c);
+    }
+    resultat
+}
+
+// ========================================================
+// CONJUGUER
+// ========================================================
+
+struct Verbe {
+    racine: Racine,
+    forme: Forme,
+    classe: ClasseRacine,
+    voyelles: VoyellesI,
+    radicaux: Radicaux,
+    // Nom passé à l'orthographe (assimilation du ت pour افتعل)
+    scheme_nom: String,
+}
+
+impl Verbe {
+    fn new(racine: Racine, forme: Forme, voyelles: VoyellesI) -> Verbe {
+        let classe = classifier(&racine);
+        let mut radicaux = radicaux(forme, voyelles);
+        let mut scheme_nom = forme.modele();
+
+        // مثال : le و/ي initial s'assimile au ت de la forme VIII (اتَّعَدَ)
+        let r = racine.lettres();
+        let assimilee =
+            matches!(classe, ClasseRacine::Assimilee | ClasseRacine::Lafif) && est_faible(r[0]);
+        if assimilee && forme == Forme::VIII {
+            let assimiler = |radical: &str| radical.replacen("فْت", "تّ", 1);
+            radicaux.accompli = assimiler(&radicaux.accompli);
+            radicaux.inaccompli = assimiler(&radicaux.inaccompli);
+            radicaux.accompli_passif = radicaux.accompli_passif.as_deref().map(assimiler);
+            radicaux.inaccompli_passif = radicaux.inaccompli_passif.as_deref().map(assimiler);
+            scheme_nom = String::new();
+        }
+        // ... et le و tombe à l'inaccompli actif de la forme I (يَعِدُ، يَضَعُ),
+        // sauf après une kasra à l'accompli (وَجِلَ يَوْجَلُ)
+        if assimilee
+            && forme == Forme::I
+            && r[0] == 'و'
+            && (voyelles.accompli == FATHA || voyelles.inaccompli == KASRA)
+            && let Some(reste) = radicaux.inaccompli.strip_prefix("فْ")
+        {
+            radicaux.inaccompli = reste.to_string();
+        }
+
+        Verbe {
+            racine,
+            forme,
+            classe,
+            voyelles,
+            radicaux,
+            scheme_nom,
+        }
+    }
+
+    // ناقص et لفيف : désinences propres (sauf forme IX et quadrilitères)
+    fn defectif(&self) -> bool {
+        matches!(self.classe, ClasseRacine::Defectueuse | ClasseRacine::Lafif)
+            && !matches!(self.forme, Forme::IX | Forme::QI | Forme::QII)
+    }
+
+    // أجوف : seules les formes où le 2ème radical suit un sukun ou une voyelle
+    // brève perdent ce radical (II, III, V, VI le gardent : قَوَّمَ، قَاوَمَ)
+    fn creux(&self) -> bool {
+        self.classe == ClasseRacine::Creuse
+            && matches!(
+                self.forme,
+                Forme::I | Forme::IV | Forme::VII | Forme::VIII | Forme::X
+            )
+    }
+
+    fn contracte(&self) -> bool {
+        self.forme == Forme::IX || self.classe == ClasseRacine::Geminee
+    }
+
+    // Désinences ناقص pour un temps, d'après la voyelle du 2ème radical
+    fn desinences_defectives(&self, temps: Temps, voyelle: char) -> &'static [&'static str] {
+        match (temps, voyelle) {
+            (Temps::Accompli, KASRA) | (Temps::AccompliPassif, _) => &ACCOMPLI_KASRA,
+            (Temps::Accompli, _) if self.forme == Forme::I && self.racine.lettres()[2] == 'و' => {
+                &ACCOMPLI_WAW
+            }
+            (Temps::Accompli, _) => &ACCOMPLI_YA,
+            (Temps::Imperatif, KASRA) => &IMPERATIF_KASRA,
+            (Temps::Imperatif, DAMMA) => &IMPERATIF_DAMMA,
+            (Temps::Imperatif, _) => &IMPERATIF_FATHA,
+            (_, KASRA) => &INACCOMPLI_KASRA,
+            (_, DAMMA) => &INACCOMPLI_DAMMA,
+            _ => &INACCOMPLI_FATHA,
+        }
+    }
+
+    // Radical + désinence (personne n°`i` du temps), avant remplissage
+    fn flechir(&self, temps: Temps, radical: &str, i: usize, desinence: &str) -> String {
+        if self.defectif() {
+            let (tronc, voyelle) = tronquer(radical);
+            return tronc + self.desinences_defectives(temps, voyelle)[i];
+        }
+
+        let devant_voyelle = !desinence.starts_with(SUKUN);
+        let radical = if self.creux() {
+            let forcee = (self.forme == Forme::I && temps == Temps::Accompli && !devant_voyelle)
+                .then(|| {
+                    if self.racine.lettres()[1] == 'و' && self.voyelles.inaccompli == DAMMA {
+                        DAMMA
+                    } else {
+                        KASRA
+                    }
+                });
+            radical_creux(radical, devant_voyelle, forcee)
+        } else if self.contracte() && devant_voyelle {
+            contracter(radical)
+        } else {
+            radical.to_string()
+        };
+        radical + desinence
+    }
+
+    // Remplir un modèle avec la racine et l'orthographier
+    fn realiser(&self, modele: &str) -> String {
+        let mot = generer_gabarit(self.racine, &Gabarit::depuis_nom(modele), &self.scheme_nom);
+        sans_sukun_de_prolongation(&mot)
+    }
+
+    fn accompli(&self, temps: Temps, radical: &str) -> TempsConjugue {
+        let formes = PERSONNES
+            .iter()
+            .enumerate()
+            .map(|(i, &(personne, pronom, _, desinence, _))| {
+                let mot = self.realiser(&self.flechir(temps, radical, i, desinence));
+                forme_conjuguee(personne, pronom, mot)
+            })
+            .collect();
+        temps_conjugue(temps, formes)
+    }
+
+    fn inaccompli(&self, temps: Temps, radical: &str, prefixe_damma: bool) -> TempsConjugue {
+        let voyelle = if prefixe_damma { DAMMA } else { FATHA };
+        let formes = PERSONNES
+            .iter()
+            .enumerate()
+            .map(|(i, &(personne, pronom, prefixe, _, desinence))| {
+                let modele = format!(
+                    "{}{}{}",
+                    prefixe,
+                    voyelle,
+                    self.flechir(temps, radical, i, desinence)
+                );
+                forme_conjuguee(personne, pronom, self.realiser(&modele))
+            })
+            .collect();
+        temps_conjugue(temps, formes)
+    }
+
+    // Impératif : l'inaccompli sans préfixe de personne, précédé au besoin d'un
+    // alif (vocalisé ici, pour la lecture : اُكْتُبْ، اِسْتَفْعِلْ)
+    fn imperatif(&self) -> TempsConjugue {
+        let formes = IMPERATIF
+            .iter()
+            .enumerate()
+            .map(|(k, &(i, desinence))| {
+                let (personne, pronom, ..) = PERSONNES[i];
+                let tige = self.flechir(Temps::Imperatif, &self.radicaux.inaccompli, k, desinence);
+                let sukun_initial = tige.chars().nth(1) == Some(SUKUN);
+                let prefixe = match self.radicaux.imperatif {
+                    PrefixeImperatif::Aucun => String::new(),
+                    PrefixeImperatif::Hamza => format!("أ{}", FATHA),
+                    PrefixeImperatif::Wasl => format!("ا{}", KASRA),
+                    PrefixeImperatif::WaslSiSukun if sukun_initial => {
+                        let voyelle = if self.voyelles.inaccompli == DAMMA {
+                            DAMMA
+                        } else {
+                            KASRA
+                        };
+                        format!("ا{}", voyelle)
+                    }
+                    PrefixeImperatif::WaslSiSukun => String::new(),
+                };
+                forme_conjuguee(personne, pronom, self.realiser(&(prefixe + &tige)))
+            })
+            .collect();
+        temps_conjugue(Temps::Imperatif, formes)
+    }
+}
+
+fn forme_conjuguee(personne: &str, pronom: &str, mot: String) -> FormeConjuguee {
+    FormeConjuguee {
+        personne: personne.to_string(),
+        pronom: pronom.to_string(),
+        mot,
+    }
+}
+
+fn temps_conjugue(temps: Temps, formes: Vec<FormeConjuguee>) -> TempsConjugue {
+    TempsConjugue {
+        temps: temps.code().to_string(),
+        nom: temps.nom_arabe().to_string(),
+        formes,
+    }
+}
+
+// Tableau de conjugaison d'une racine à une forme
+// `voyelles` ne concerne que la forme I (par défaut : voir `VoyellesI::par_defaut`)
+// Erreur si la forme n'a pas le nombre de radicaux de la racine
+pub fn conjuguer(
+    racine: Racine,
+    forme: Forme,
+    voyelles: Option<VoyellesI>,
+) -> Result<TableConjugaison, MorphoError> {
+    if racine.longueur() != forme.nombre_radicaux() {
+        return Err(MorphoError::SchemeIncompatible {
+            scheme: forme.modele(),
+            racine,
+        });
+    }
+
+    let voyelles = voyelles.unwrap_or_else(|| VoyellesI::par_defaut(&racine));
+    let verbe = Verbe::new(racine, forme, voyelles);
+    let r = &verbe.radicaux;
+
+    let mut temps = vec![verbe.accompli(Temps::Accompli, &r.accompli)];
+    if let Some(passif) = &r.accompli_passif {
+        temps.push(verbe.accompli(Temps::AccompliPassif, passif));
+    }
+    temps.push(verbe.inaccompli(Temps::Inaccompli, &r.inaccompli, r.prefixe_damma));
+    if let Some(passif) = &r.inaccompli_passif {
+        temps.push(verbe.inaccompli(Temps::InaccompliPassif, passif, true));
+    }
+    temps.push(verbe.imperatif());
+
+    Ok(TableConjugaison {
+        racine: racine.to_string(),
+        forme: forme.numero().to_string(),
+        modele: forme.modele_vocalise().to_string(),
+        classe: verbe.classe.nom_arabe().to_string(),
+        voyelles: (forme == Forme::I).then(|| voyelles.code()),
+        temps,
+    })
+}
+
+// ========================================================
+// RECONNAISSANCE D'UN MOT CONJUGUÉ
+// ========================================================
+
+// Place d'un mot dans le tableau de conjugaison de sa racine
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conjugaison {
+    pub temps: Temps,
+    // "3mp", "2fs"... (voir PERSONNES)
+    pub personne: String,
+    // باب de la forme I ("a-u"), absent pour les autres formes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voyelles: Option<String>,
+}
+
+impl Conjugaison {
+    // "inaccompli 3mp"
+    pub fn etiquette(&self) -> String {
+        format!("{} {}", self.temps.code(), self.personne)
+    }
+
+    // Propriétés du mot conjugué : celles du schème de l'accompli (forme,
+    // nature), avec l'aspect, la voix, le genre et le nombre de la personne
+    pub fn proprietes(&self, accompli: Proprietes) -> Proprietes {
+        let (aspect, voix) = match self.temps {
+            Temps::Accompli => (Aspect::Accompli, Voix::Actif),
+            Temps::AccompliPassif => (Aspect::Accompli, Voix::Passif),
+            Temps::Inaccompli => (Aspect::Inaccompli, Voix::Actif),
+            Temps::InaccompliPassif => (Aspect::Inaccompli, Voix::Passif),
+            Temps::Imperatif => (Aspect::Imperatif, Voix::Actif),
+        };
+        let nombre = match self.personne.chars().last() {
+            Some('s') => Nombre::Singulier,
+            Some('d') => Nombre::Duel,
+            _ => Nombre::Pluriel,
+        };
+        let genre = if self.personne.contains('m') {
+            Some(Genre::Masculin)
+        } else if self.personne.contains('f') {
+            Some(Genre::Feminin)
+        } else {
+            None
+        };
+        Proprietes {
+            aspect: Some(aspect),
+            voix: Some(voix),
+            nombre: Some(nombre),
+            genre,
+            ..accompli
+        }
+    }
+}
+
+// Mots du tableau de conjugaison de la racine à cette forme qui reproduisent
+// la saisie (au niveau de détail de la saisie, voir `equivalent`), avec leur
+// place dans le tableau ; vide si aucun
+// Forme I : le باب par défaut de la racine, puis les autres seulement s'il ne
+// donne rien (une saisie nue ne reçoit ainsi qu'une vocalisation). Une saisie
+// nue d'une racine sans lettre faible s'écrit de la même façon pour tous les
+// باب : seul le premier est essayé
+pub fn reconnaitre(mot: &str, racine: Racine, forme: Forme) -> Vec<(String, Conjugaison)> {
+    let defaut = VoyellesI::par_defaut(&racine);
+    let mut essais = vec![defaut];
+    let vocalise = mot.chars().any(est_diacritique);
+    let faible = !matches!(
+        classifier(&racine),
+        ClasseRacine::Saine | ClasseRacine::Hamzee | ClasseRacine::Geminee
+    );
+    if forme == Forme::I && (vocalise || faible) {
+        essais.extend(VoyellesI::TOUTES.into_iter().filter(|v| *v != defaut));
+    }
+
+    for voyelles in essais {
+        let Ok(tableau) = conjuguer(racine, forme, Some(voyelles)) else {
+            break;
+        };
+        let mut trouvees: Vec<(String, Conjugaison)> = Vec::new();
+        for temps in Temps::TOUS {
+            let Some(conjugue) = tableau.temps.iter().find(|t| t.temps == temps.code()) else {
+                continue;
+            };
+            for f in conjugue.formes.iter().filter(|f| equivalent(mot, &f.mot)) {
+                let conjugaison = Conjugaison {
+                    temps,
+                    personne: f.personne.clone(),
+                    voyelles: tableau.voyelles.clone(),
+                };
+                trouvees.push((f.mot.clone(), conjugaison));
+            }
+        }
+        if !trouvees.is_empty() {
+            return trouvees;
+        }
+    }
+    Vec::new()
+}
+
+// Mots conjugués de la forme pour la racine ف ع ل (ف ع ل ل), sans voyelles
+// et sans doublons : les gabarits sur lesquels superposer un verbe conjugué
+// (يفعلون → ي [1] [2] [3] ون, voir `Gabarit::depuis_nom`)
+// Ce sont les formes d'une racine saine : un verbe faible dont une lettre
+// tombe (قالوا، يرمون) n'est retrouvé que si sa racine est donnée
+// Calculés une fois pour toutes les formes (l'analyse d'un texte les
+// parcourt pour chaque mot)
+pub fn modeles_conjugues(forme: Forme) -> &'static [String] {
+    static MODELES: OnceLock<Vec<Vec<String>>> = OnceLock::new();
+    let modeles = MODELES.get_or_init(|| Forme::TOUTES.into_iter().map(calculer_modeles).collect());
+    let indice = Forme::TOUTES.iter().position(|f| *f == forme).unwrap_or(0);
+    &modeles[indice]
+}
+
+fn calculer_modeles(forme: Forme) -> Vec<String> {
+    let lettres: &[char] = match forme.nombre_radicaux() {
+        4 => &['ف', 'ع', 'ل', 'ل'],
+        _ => &['ف', 'ع', 'ل'],
+    };
+    let Ok(racine) = Racine::depuis_lettres(lettres) else {
+        return Vec::new();
+    };
+    let Ok(tableau) = conjuguer(racine, forme, None) else {
+        return Vec::new();
+    };
+    let mut modeles: Vec<String> = Vec::new();
+    for f in tableau.temps.iter().flat_map(|t| &t.formes) {
+        let modele = sans_diacritiques(&f.mot);
+        if !modeles.contains(&modele) {
+            modeles.push(modele);
+        }
+    }
+    modeles
+}
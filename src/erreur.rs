@@ -0,0 +1,175 @@
+// ============================================================================
+// ERREURS DU MOTEUR MORPHOLOGIQUE
+// ============================================================================
+// Une seule énumération pour toute la crate : l'arbre, la table des schèmes et
+// le moteur de dérivation retournent `Result<_, MorphoError>` au lieu de
+// `false`, `0` ou d'un message affiché.
+//
+// Côté WebAssembly (lib.rs), chaque erreur devient une exception JavaScript
+// portant un `code` stable (ex. "RACINE_INTROUVABLE") et un `message` lisible.
+
+use std::fmt;
+
+use crate::gabarit::ErreurPattern;
+use crate::racine::Racine;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MorphoError {
+    // La racine n'est pas dans l'arbre
+    RacineIntrouvable(Racine),
+    // La racine existe mais ne porte pas ce dérivé
+    DeriveIntrouvable { racine: Racine, mot: String },
+    // Aucun schème de ce nom dans la table
+    SchemeIntrouvable(String),
+    // Un schème porte déjà cet identifiant (utiliser `remplacer` pour le modifier)
+    SchemeExistant(String),
+    // Modèle partagé par plusieurs schèmes : il faut donner un identifiant
+    SchemeAmbigu { modele: String, ids: Vec<String> },
+    // Le schème n'a pas le même nombre de radicaux que la racine
+    SchemeIncompatible { scheme: String, racine: Racine },
+    // Une racine doit avoir 3 ou 4 radicaux
+    LongueurRacine(usize),
+    // Radical qui n'est pas une consonne arabe admise (position à partir de 1)
+    LettreNonArabe { lettre: char, position: usize },
+    // Fusion refusée : un schème importé diffère du schème local de même clé
+    ConflitScheme(String),
+    // La table des schèmes a atteint sa capacité maximale
+    TablePleine { capacite: usize },
+    // Texte qui ne désigne aucune forme verbale (voir conjugaison.rs)
+    FormeInconnue(String),
+    // Voyelles de la forme I mal écrites (attendu : "a-u", "i-a"...)
+    VoyellesInvalides(String),
+    // Pattern de schème mal formé (voir gabarit.rs)
+    Pattern(ErreurPattern),
+    // Ligne invalide dans un fichier ou un texte de racines (ligne à partir de 1)
+    Syntaxe { ligne: usize, message: String },
+    // Fichier illisible ou impossible à écrire
+    Fichier { chemin: String, message: String },
+    // Données JSON invalides (import)
+    Json(String),
+    // Résultat impossible à convertir en valeur JavaScript
+    Serialisation(String),
+    // Lexique écrit par une version plus récente du moteur
+    VersionLexique(String),
+    // Borne, préfixe ou motif de recherche de racines mal formé (voir requete.rs)
+    RequeteInvalide(String),
+    // Fonction libre de l'API appelée avant init_app()
+    NonInitialise,
+}
+
+impl MorphoError {
+    // Code stable, utilisé par JavaScript pour distinguer les erreurs
+    pub fn code(&self) -> &'static str {
+        match self {
+            MorphoError::RacineIntrouvable(_) => "RACINE_INTROUVABLE",
+            MorphoError::DeriveIntrouvable { .. } => "DERIVE_INTROUVABLE",
+            MorphoError::SchemeIntrouvable(_) => "SCHEME_INTROUVABLE",
+            MorphoError::SchemeExistant(_) => "SCHEME_EXISTANT",
+            MorphoError::SchemeAmbigu { .. } => "SCHEME_AMBIGU",
+            MorphoError::SchemeIncompatible { .. } => "SCHEME_INCOMPATIBLE",
+            MorphoError::LongueurRacine(_) => "LONGUEUR_RACINE",
+            MorphoError::LettreNonArabe { .. } => "LETTRE_NON_ARABE",
+            MorphoError::ConflitScheme(_) => "CONFLIT_SCHEME",
+            MorphoError::TablePleine { .. } => "TABLE_PLEINE",
+            MorphoError::FormeInconnue(_) => "FORME_INCONNUE",
+            MorphoError::VoyellesInvalides(_) => "VOYELLES_INVALIDES",
+            MorphoError::Pattern(_) => "PATTERN_INVALIDE",
+            MorphoError::Syntaxe { .. } => "SYNTAXE",
+            MorphoError::Fichier { .. } => "FICHIER",
+            MorphoError::Json(_) => "JSON_INVALIDE",
+            MorphoError::Serialisation(_) => "SERIALISATION",
+            MorphoError::VersionLexique(_) => "VERSION_LEXIQUE",
+            MorphoError::RequeteInvalide(_) => "REQUETE_INVALIDE",
+            MorphoError::NonInitialise => "NON_INITIALISE",
+        }
+    }
+}
+
+impl fmt::Display for MorphoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MorphoError::RacineIntrouvable(r) => {
+                write!(f, "racine '{}' absente de l'arbre", r)
+            }
+            MorphoError::DeriveIntrouvable { racine, mot } => {
+                write!(f, "la racine '{}' n'a pas de dérivé '{}'", racine, mot)
+            }
+            MorphoError::SchemeIntrouvable(nom) => write!(f, "schème '{}' inconnu", nom),
+            MorphoError::SchemeExistant(id) => write!(f, "le schème '{}' existe déjà", id),
+            MorphoError::SchemeAmbigu { modele, ids } => write!(
+                f,
+                "le modèle '{}' désigne plusieurs schèmes ({}) : préciser l'identifiant",
+                modele,
+                ids.join(", ")
+            ),
+            MorphoError::SchemeIncompatible { scheme, racine } => write!(
+                f,
+                "le schème '{}' ne s'applique pas à la racine '{}' ({} radicaux)",
+                scheme,
+                racine,
+                racine.longueur()
+            ),
+            MorphoError::LongueurRacine(n) => {
+                write!(f, "une racine doit contenir 3 ou 4 lettres ({} reçues)", n)
+            }
+            MorphoError::LettreNonArabe { lettre, position } => write!(
+                f,
+                "radical '{}' (U+{:04X}) en position {} : ce n'est pas une consonne arabe",
+                lettre, *lettre as u32, position
+            ),
+            MorphoError::ConflitScheme(cle) => write!(
+                f,
+                "le schème importé '{}' diffère du schème local de même nom",
+                cle
+            ),
+            MorphoError::TablePleine { capacite } => write!(
+                f,
+                "table des schèmes pleine (capacité maximale {} atteinte)",
+                capacite
+            ),
+            MorphoError::FormeInconnue(texte) => write!(
+                f,
+                "'{}' ne désigne aucune forme verbale (I à X, QI, QII ou modèle comme استفعل)",
+                texte
+            ),
+            MorphoError::VoyellesInvalides(texte) => write!(
+                f,
+                "voyelles '{}' invalides (attendu : accompli-inaccompli parmi a, i, u, ex. a-u)",
+                texte
+            ),
+            MorphoError::Pattern(e) => write!(f, "pattern invalide, {}", e),
+            MorphoError::Syntaxe { ligne, message } => write!(f, "ligne {} : {}", ligne, message),
+            MorphoError::Fichier { chemin, message } => {
+                write!(f, "fichier '{}' : {}", chemin, message)
+            }
+            MorphoError::Json(message) => write!(f, "JSON invalide : {}", message),
+            MorphoError::Serialisation(message) => {
+                write!(f, "résultat impossible à convertir : {}", message)
+            }
+            MorphoError::VersionLexique(v) => {
+                write!(f, "version de lexique '{}' non supportée", v)
+            }
+            MorphoError::RequeteInvalide(message) => {
+                write!(f, "recherche de racines invalide : {}", message)
+            }
+            MorphoError::NonInitialise => {
+                write!(f, "application non initialisée (appeler init_app)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MorphoError {}
+
+impl From<ErreurPattern> for MorphoError {
+    fn from(e: ErreurPattern) -> Self {
+        MorphoError::Pattern(e)
+    }
+}
+
+// Conversion d'un résultat en JsValue (moteur.rs) : une erreur au lieu d'un panic
+impl From<serde_wasm_bindgen::Error> for MorphoError {
+    fn from(e: serde_wasm_bindgen::Error) -> Self {
+        MorphoError::Serialisation(e.to_string())
+    }
+}
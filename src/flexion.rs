@@ -0,0 +1,223 @@
+// ============================================================================
+// FLEXION : genre, nombre et cas des noms, adjectifs et participes
+// ============================================================================
+// Un schème produit la forme de base (masculin singulier pour un participe :
+// كَاتِب). Les désinences régulières s'ajoutent par-dessus :
+//   ة          féminin singulier        كَاتِب → كَاتِبَة
+//   ان / ين    duel (nominatif / cas oblique : accusatif et génitif)
+//   ون / ين    pluriel masculin sain     كَاتِبُون، كَاتِبِين
+//   تان / تين  duel féminin : le ة devient ت    كَاتِبَتَان
+//   ات         pluriel féminin sain : le ة tombe   كَاتِبَات
+//
+// Quelles désinences pour quel schème ? (d'après ses propriétés, voir proprietes.rs)
+//   - participe, adjectif masculin singulier : toutes
+//   - nom, masdar masculin : le duel seulement (مَكْتَبَان)
+//   - nom, masdar, participe féminin (terminé par ة) : duel féminin et ات
+//   - verbes, pluriels, schèmes sans nature : aucune
+//
+// Le participe ناقص (رَامٍ) retrouve son ي devant une voyelle : رَامِيَة،
+// رَامِيَان، mais رَامُون au pluriel masculin. Une base en ى ou ا (مَرْمًى)
+// n'est pas fléchie.
+
+use serde::{Deserialize, Serialize};
+
+use crate::gabarit::{SHADDA, est_diacritique, sans_diacritiques};
+use crate::proprietes::{Genre, Nature, Nombre, Proprietes};
+
+// Tanwin du nom ناقص (رَامٍ)
+const KASRATAN: char = '\u{064D}';
+
+// Cas marqué par les désinences du duel et du pluriel masculin
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Cas {
+    // ان، ون
+    Nominatif,
+    // ين : accusatif et génitif
+    Oblique,
+}
+
+impl Cas {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Cas::Nominatif => "nominatif",
+            Cas::Oblique => "oblique",
+        }
+    }
+}
+
+// Traits fléchis d'un mot
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Flexion {
+    pub genre: Genre,
+    pub nombre: Nombre,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cas: Option<Cas>,
+}
+
+impl Flexion {
+    const fn new(genre: Genre, nombre: Nombre, cas: Option<Cas>) -> Flexion {
+        Flexion { genre, nombre, cas }
+    }
+
+    pub const FEMININ: Flexion = Flexion::new(Genre::Feminin, Nombre::Singulier, None);
+    pub const DUEL: Flexion = Flexion::new(Genre::Masculin, Nombre::Duel, Some(Cas::Nominatif));
+    pub const DUEL_OBLIQUE: Flexion =
+        Flexion::new(Genre::Masculin, Nombre::Duel, Some(Cas::Oblique));
+    pub const PLURIEL: Flexion =
+        Flexion::new(Genre::Masculin, Nombre::Pluriel, Some(Cas::Nominatif));
+    pub const PLURIEL_OBLIQUE: Flexion =
+        Flexion::new(Genre::Masculin, Nombre::Pluriel, Some(Cas::Oblique));
+    pub const DUEL_FEMININ: Flexion =
+        Flexion::new(Genre::Feminin, Nombre::Duel, Some(Cas::Nominatif));
+    pub const DUEL_FEMININ_OBLIQUE: Flexion =
+        Flexion::new(Genre::Feminin, Nombre::Duel, Some(Cas::Oblique));
+    pub const PLURIEL_FEMININ: Flexion = Flexion::new(Genre::Feminin, Nombre::Pluriel, None);
+
+    // Désinence vocalisée, voyelle de la dernière lettre de la base comprise
+    pub fn desinence(&self) -> &'static str {
+        match (self.genre, self.nombre, self.cas) {
+            (Genre::Feminin, Nombre::Singulier, _) => "َة",
+            (Genre::Masculin, Nombre::Duel, Some(Cas::Oblique)) => "َيْن",
+            (Genre::Masculin, Nombre::Duel, _) => "َان",
+            (Genre::Masculin, Nombre::Pluriel, Some(Cas::Oblique)) => "ِين",
+            (Genre::Masculin, Nombre::Pluriel, _) => "ُون",
+            (Genre::Feminin, Nombre::Duel, Some(Cas::Oblique)) => "َتَيْن",
+            (Genre::Feminin, Nombre::Duel, _) => "َتَان",
+            (Genre::Feminin, Nombre::Pluriel, _) => "َات",
+            (Genre::Masculin, Nombre::Singulier, _) => "",
+        }
+    }
+
+    // "feminin singulier", "masculin pluriel oblique"
+    pub fn etiquette(&self) -> String {
+        let mut etiquette = format!("{} {}", self.genre.code(), self.nombre.code());
+        if let Some(cas) = self.cas {
+            etiquette.push(' ');
+            etiquette.push_str(cas.code());
+        }
+        etiquette
+    }
+}
+
+// Une forme fléchie et ses traits
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormeFlechie {
+    pub mot: String,
+    pub flexion: Flexion,
+}
+
+// Toutes les formes fléchies d'une base produite par un schème (la base
+// elle-même n'en fait pas partie)
+pub fn flechir(base: &str, proprietes: &Proprietes) -> Vec<FormeFlechie> {
+    flexions_admises(base, proprietes)
+        .into_iter()
+        .map(|flexion| FormeFlechie {
+            mot: joindre(base, flexion),
+            flexion,
+        })
+        .collect()
+}
+
+// Désinences admises par la base, d'après les propriétés de son schème
+fn flexions_admises(base: &str, proprietes: &Proprietes) -> Vec<Flexion> {
+    let Some(nature) = proprietes.nature else {
+        return Vec::new();
+    };
+    let nominal = matches!(
+        nature,
+        Nature::Nom | Nature::Adjectif | Nature::Participe | Nature::Masdar
+    );
+    let derniere = sans_diacritiques(base).chars().last();
+    if !nominal
+        || proprietes.nombre.is_some_and(|n| n != Nombre::Singulier)
+        || matches!(derniere, None | Some('ى') | Some('ا'))
+    {
+        return Vec::new();
+    }
+
+    let feminin = proprietes.genre == Some(Genre::Feminin) || derniere == Some('ة');
+    if feminin {
+        return vec![
+            Flexion::DUEL_FEMININ,
+            Flexion::DUEL_FEMININ_OBLIQUE,
+            Flexion::PLURIEL_FEMININ,
+        ];
+    }
+    let mut flexions = vec![Flexion::DUEL, Flexion::DUEL_OBLIQUE];
+    // Seuls les adjectifs et participes (êtres animés) ont les pluriels sains
+    // masculins et le féminin en ة
+    if matches!(nature, Nature::Adjectif | Nature::Participe) {
+        flexions.extend([
+            Flexion::FEMININ,
+            Flexion::PLURIEL,
+            Flexion::PLURIEL_OBLIQUE,
+            Flexion::DUEL_FEMININ,
+            Flexion::DUEL_FEMININ_OBLIQUE,
+            Flexion::PLURIEL_FEMININ,
+        ]);
+    }
+    flexions
+}
+
+// Ajouter la désinence à la base
+fn joindre(base: &str, flexion: Flexion) -> String {
+    // Nom ناقص : le ي radical réapparaît devant une désinence en a
+    if let Some(radical) = base.strip_suffix(KASRATAN) {
+        return match (flexion.genre, flexion.nombre) {
+            (Genre::Masculin, Nombre::Pluriel) => format!("{}{}", radical, flexion.desinence()),
+            _ => format!("{}ِي{}", radical, flexion.desinence()),
+        };
+    }
+
+    // La voyelle finale et le ة de la base sont remplacés par la désinence
+    let mut radical = sans_voyelle_finale(base);
+    if let Some(sans_ta) = radical.strip_suffix('ة') {
+        radical = sans_voyelle_finale(sans_ta);
+    }
+    format!("{}{}", radical, flexion.desinence())
+}
+
+// Retirer les voyelles qui suivent la dernière lettre (la shadda reste)
+fn sans_voyelle_finale(mot: &str) -> String {
+    mot.trim_end_matches(|c: char| est_diacritique(c) && c != SHADDA)
+        .to_string()
+}
+
+// Bases nues possibles d'un mot fléchi (pour l'analyse inverse) :
+// كاتبون → كاتب ; كاتبتان → كاتبة ; كاتبات → كاتبة, كاتب ; رامية → رامي, رام
+pub fn bases_possibles(mot: &str) -> Vec<String> {
+    const DESINENCES: [(&str, &str); 7] = [
+        ("تان", "ة"),
+        ("تين", "ة"),
+        ("ات", "ة"),
+        ("ات", ""),
+        ("ان", ""),
+        ("ين", ""),
+        ("ون", ""),
+    ];
+    let mot = sans_diacritiques(mot);
+    let mut bases: Vec<String> = Vec::new();
+    let mut ajouter = |base: String| {
+        if base.chars().count() >= 2 && !bases.contains(&base) {
+            bases.push(base);
+        }
+    };
+
+    for (desinence, remplacement) in DESINENCES {
+        if let Some(radical) = mot.strip_suffix(desinence) {
+            ajouter(format!("{}{}", radical, remplacement));
+            // رامون، راميان : le ي du ناقص fait partie de la désinence
+            if let Some(sans_ya) = radical.strip_suffix('ي') {
+                ajouter(sans_ya.to_string());
+            }
+        }
+    }
+    if let Some(radical) = mot.strip_suffix('ة') {
+        ajouter(radical.to_string());
+        if let Some(sans_ya) = radical.strip_suffix('ي') {
+            ajouter(sans_ya.to_string());
+        }
+    }
+    bases
+}
@@ -0,0 +1,237 @@
+// ============================================================================
+// GABARIT D'UN SCHÈME (pattern explicite)
+// ============================================================================
+// Le champ `pattern` d'un schème décrit ses cases une par une, séparées par '-' :
+//
+//   "ف-ا-ع-ل"      → [radical 1] ا [radical 2] [radical 3]
+//   "ف-ع-ّ-ل"      → [radical 1] [radical 2] shadda [radical 3]
+//   "ف-ع-ل-ل"      → le 2ème ل est le 4ème radical (quadrilitère)
+//   "1-ا-2-3"      → radicaux numérotés explicitement (équivalent à ف-ا-ع-ل)
+//   "=ل-ف-ا-ع-ل"   → '=' force une lettre FIXE : ici un لـ préfixé, pas un radical
+//   "فَ-ا-عِ-لٌ"    → une case peut porter ses voyelles (diacritiques)
+//   "فّ" ou "ف-ّ"  → gémination (shadda) du radical
+//
+// Contrairement à l'ancienne substitution sur le nom, une lettre ع ou ل fixe
+// ne peut plus être confondue avec un radical.
+
+use std::fmt;
+
+// Shadda : marque de gémination
+pub const SHADDA: char = 'ّ';
+
+// Une case du gabarit
+#[derive(Clone, Debug, PartialEq)]
+pub enum Segment {
+    Radical(usize),    // radical n°1..4 de la racine
+    Lettre(char),      // lettre fixe du schème (ا, م, ت, ...)
+    Diacritique(char), // voyelle brève, sukun ou tanwin
+    Gemination,        // shadda
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gabarit {
+    pub segments: Vec<Segment>,
+}
+
+// Erreur de syntaxe dans un pattern (position = numéro de case, à partir de 1)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErreurPattern {
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ErreurPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "case {} : {}", self.position, self.message)
+    }
+}
+
+// Voyelles brèves, sukun et tanwin (U+064B à U+0652, sauf la shadda)
+pub fn est_diacritique(c: char) -> bool {
+    ('\u{064B}'..='\u{0652}').contains(&c) && c != SHADDA
+}
+
+// Projection sans voyelles : كَتَّبَ → كتّب (la shadda, qui note une lettre
+// redoublée, est conservée ; c'est la forme affichée par l'ancien moteur)
+pub fn sans_diacritiques(mot: &str) -> String {
+    mot.chars().filter(|c| !est_diacritique(*c)).collect()
+}
+
+// Découper un mot en lettres de base, chacune avec ses diacritiques (shadda
+// comprise). None si le mot commence par un diacritique.
+fn decouper(mot: &str) -> Option<Vec<(char, Vec<char>)>> {
+    let mut lettres: Vec<(char, Vec<char>)> = Vec::new();
+    for c in mot.chars() {
+        if c == SHADDA || est_diacritique(c) {
+            lettres.last_mut()?.1.push(c);
+        } else {
+            lettres.push((c, Vec::new()));
+        }
+    }
+    Some(lettres)
+}
+
+// Comparer une saisie à une forme vocalisée, au niveau de détail de la saisie :
+// mêmes lettres de base, et chaque diacritique saisi doit figurer sur la même
+// lettre de la forme (l'ordre voyelle/shadda est indifférent)
+//   كاتب, كَاتِب, كَاتب  → correspondent à كَاتِب
+//   كُتِب              → ne correspond pas à كَاتِب
+pub fn correspond(saisie: &str, forme: &str) -> bool {
+    let (Some(saisie), Some(forme)) = (decouper(saisie), decouper(forme)) else {
+        return false;
+    };
+    saisie.len() == forme.len()
+        && saisie
+            .iter()
+            .zip(&forme)
+            .all(|((c, marques), (f, attendues))| {
+                c == f && marques.iter().all(|m| attendues.contains(m))
+            })
+}
+
+impl Gabarit {
+    // Analyser un pattern explicite ("ف-ا-ع-ل")
+    pub fn parse(pattern: &str) -> Result<Gabarit, ErreurPattern> {
+        let mut segments = Vec::new();
+        let mut nb_lam = 0; // pour distinguer le 3ème radical (1er ل) du 4ème (2ème ل)
+
+        for (i, case) in pattern.split('-').enumerate() {
+            let position = i + 1;
+            let erreur = |message: &str| ErreurPattern {
+                position,
+                message: message.to_string(),
+            };
+
+            let case = case.trim();
+            let mut chars = case.chars();
+
+            // 1) La lettre de base de la case
+            let base = match chars.next() {
+                None => return Err(erreur("case vide")),
+                Some('=') => match chars.next() {
+                    Some(c) if c.is_alphabetic() => Segment::Lettre(c),
+                    _ => return Err(erreur("'=' doit être suivi d'une lettre")),
+                },
+                Some('ف') | Some('1') => Segment::Radical(1),
+                Some('ع') | Some('2') => Segment::Radical(2),
+                Some('3') => Segment::Radical(3),
+                Some('4') => Segment::Radical(4),
+                Some('ل') => {
+                    nb_lam += 1;
+                    Segment::Radical(2 + nb_lam)
+                }
+                // Shadda ou voyelle seule : s'attache à la case précédente
+                Some(SHADDA) => Segment::Gemination,
+                Some(c) if est_diacritique(c) => Segment::Diacritique(c),
+                Some(c) if c.is_alphabetic() => Segment::Lettre(c),
+                Some(c) => return Err(erreur(&format!("caractère inattendu '{}'", c))),
+            };
+
+            if matches!(base, Segment::Gemination | Segment::Diacritique(_)) && segments.is_empty()
+            {
+                return Err(erreur("un diacritique ne peut pas commencer le pattern"));
+            }
+            if let Segment::Radical(n) = base
+                && n > 4
+            {
+                return Err(erreur("un schème a au plus 4 radicaux"));
+            }
+            segments.push(base);
+
+            // 2) Les diacritiques éventuels qui suivent la lettre
+            for c in chars {
+                if c == SHADDA {
+                    segments.push(Segment::Gemination);
+                } else if est_diacritique(c) {
+                    segments.push(Segment::Diacritique(c));
+                } else {
+                    return Err(erreur(&format!(
+                        "une case contient une seule lettre ('{}' en trop)",
+                        c
+                    )));
+                }
+            }
+        }
+
+        let gabarit = Gabarit { segments };
+        gabarit.verifier_radicaux()?;
+        Ok(gabarit)
+    }
+
+    // Construire un gabarit à partir d'un modèle écrit d'un seul bloc ("فاعل")
+    // Chaque ف/ع/ل est un radical (le 2ème ل = 4ème radical), le reste est fixe
+    // Sert aux variantes internes des racines faibles et aux schèmes sans pattern
+    pub fn depuis_nom(nom: &str) -> Gabarit {
+        let mut segments = Vec::new();
+        let mut nb_lam = 0;
+        for c in nom.chars() {
+            let segment = match c {
+                'ف' => Segment::Radical(1),
+                'ع' => Segment::Radical(2),
+                'ل' => {
+                    nb_lam += 1;
+                    Segment::Radical(2 + nb_lam)
+                }
+                SHADDA => Segment::Gemination,
+                c if est_diacritique(c) => Segment::Diacritique(c),
+                c => Segment::Lettre(c),
+            };
+            segments.push(segment);
+        }
+        Gabarit { segments }
+    }
+
+    // Chaque radical 1..n doit apparaître au moins une fois, sans trou
+    pub fn verifier_radicaux(&self) -> Result<(), ErreurPattern> {
+        let n = self.nombre_radicaux();
+        if n < 3 {
+            return Err(ErreurPattern {
+                position: self.segments.len(),
+                message: "le pattern doit contenir au moins 3 radicaux (ف, ع, ل)".to_string(),
+            });
+        }
+        for k in 1..=n {
+            if !self.segments.contains(&Segment::Radical(k)) {
+                return Err(ErreurPattern {
+                    position: self.segments.len(),
+                    message: format!("le radical n°{} est absent du pattern", k),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    // Nombre de radicaux attendus (le plus grand numéro de radical)
+    pub fn nombre_radicaux(&self) -> usize {
+        self.segments
+            .iter()
+            .filter_map(|s| match s {
+                Segment::Radical(n) => Some(*n),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    // Remplir le gabarit avec les radicaux d'une racine
+    // Chaque lettre est accompagnée d'un booléen "vient de la racine"
+    // Un radical absent (4ème radical pour une racine trilitère) redouble le dernier (جلبب)
+    pub fn remplir(&self, radicaux: &[char]) -> Vec<(char, bool)> {
+        self.segments
+            .iter()
+            .map(|s| match s {
+                Segment::Radical(n) => {
+                    let c = radicaux
+                        .get(n - 1)
+                        .or(radicaux.last())
+                        .copied()
+                        .unwrap_or('\0');
+                    (c, true)
+                }
+                Segment::Lettre(c) => (*c, false),
+                Segment::Diacritique(c) => (*c, false),
+                Segment::Gemination => (SHADDA, false),
+            })
+            .collect()
+    }
+}
@@ -1,10 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+use crate::erreur::MorphoError;
+use crate::gabarit::Gabarit;
+use crate::proprietes::{Criteres, Genre, Nombre, Proprietes, Voix};
+use crate::racine::Racine;
+
+// Catégorie d'un schème : regroupe la famille d'une racine à l'affichage
+// L'ordre des variantes est celui de l'affichage (verbes, puis participes...)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Categorie {
+    Verbe,
+    Participe,
+    Masdar,
+    Nom,
+    Feminin,
+    Nisba,
+    NomDeLieu,
+    NomDInstrument,
+    PlurielBrise,
+    // Schème ajouté sans catégorie (saisie utilisateur, ancien lexique)
+    #[default]
+    Autre,
+}
+
+impl Categorie {
+    pub const TOUTES: [Categorie; 10] = [
+        Categorie::Verbe,
+        Categorie::Participe,
+        Categorie::Masdar,
+        Categorie::Nom,
+        Categorie::Feminin,
+        Categorie::Nisba,
+        Categorie::NomDeLieu,
+        Categorie::NomDInstrument,
+        Categorie::PlurielBrise,
+        Categorie::Autre,
+    ];
+
+    // Nom utilisé en ligne de commande et en JSON
+    pub fn code(&self) -> &'static str {
+        match self {
+            Categorie::Verbe => "verbe",
+            Categorie::Participe => "participe",
+            Categorie::Masdar => "masdar",
+            Categorie::Nom => "nom",
+            Categorie::Feminin => "feminin",
+            Categorie::Nisba => "nisba",
+            Categorie::NomDeLieu => "nom_de_lieu",
+            Categorie::NomDInstrument => "nom_d_instrument",
+            Categorie::PlurielBrise => "pluriel_brise",
+            Categorie::Autre => "autre",
+        }
+    }
+
+    pub fn nom_arabe(&self) -> &'static str {
+        match self {
+            Categorie::Verbe => "فعل",
+            Categorie::Participe => "اسم فاعل / اسم مفعول",
+            Categorie::Masdar => "مصدر",
+            Categorie::Nom => "اسم",
+            Categorie::Feminin => "مؤنث",
+            Categorie::Nisba => "اسم منسوب",
+            Categorie::NomDeLieu => "اسم مكان / زمان",
+            Categorie::NomDInstrument => "اسم آلة",
+            Categorie::PlurielBrise => "جمع تكسير",
+            Categorie::Autre => "غير مصنف",
+        }
+    }
+
+    pub fn depuis_texte(texte: &str) -> Option<Categorie> {
+        Categorie::TOUTES.into_iter().find(|c| c.code() == texte)
+    }
+}
+
 // Structure du schème
+// `pattern` est la forme explicite ("فَ-ا-عِ-ل"), analysée une fois pour toutes
+// dans `gabarit` qui pilote la génération et la validation
+// `pluriels` : clés des schèmes de pluriel brisé possibles pour un singulier
+// (مفعل → مفاعل : مَكْتَب → مَكَاتِب)
+// `proprietes` : forme verbale, nature, voix... (voir proprietes.rs)
+// `id` est la clé du schème dans la table, unique et stable ; plusieurs schèmes
+// peuvent partager le même `nom` (forme) : فاعل (participe) et فاعل_III (verbe)
 #[derive(Clone)]
 pub struct Scheme {
+    pub id: String,
     pub nom: String,
-    #[allow(dead_code)]
     pub pattern: String,
     pub description: String,
+    pub gabarit: Gabarit,
+    pub categorie: Categorie,
+    pub pluriels: Vec<String>,
+    pub proprietes: Proprietes,
+}
+
+impl Scheme {
+    // Créer un schème en analysant son pattern
+    // Un pattern vide est déduit du nom (chaque ف/ع/ل du nom est un radical)
+    // L'identifiant vaut le nom jusqu'à l'insertion dans une table (voir try_insert)
+    pub fn new(nom: &str, pattern: &str, description: &str) -> Result<Scheme, MorphoError> {
+        let gabarit = if pattern.trim().is_empty() {
+            let gabarit = Gabarit::depuis_nom(nom);
+            gabarit.verifier_radicaux()?;
+            gabarit
+        } else {
+            Gabarit::parse(pattern)?
+        };
+        Ok(Scheme {
+            id: nom.to_string(),
+            nom: nom.to_string(),
+            pattern: pattern.to_string(),
+            description: description.to_string(),
+            gabarit,
+            categorie: Categorie::Autre,
+            pluriels: Vec::new(),
+            proprietes: Proprietes::default(),
+        })
+    }
+
+    pub fn avec_categorie(mut self, categorie: Categorie) -> Scheme {
+        self.categorie = categorie;
+        self
+    }
+
+    pub fn avec_pluriels(mut self, pluriels: &[&str]) -> Scheme {
+        self.pluriels = pluriels.iter().map(|p| p.to_string()).collect();
+        self
+    }
+
+    pub fn avec_proprietes(mut self, proprietes: Proprietes) -> Scheme {
+        self.proprietes = proprietes;
+        self
+    }
+
+    // Nombre de radicaux attendus par le schème : 3 pour فاعل, 4 pour فعلل
+    pub fn nombre_radicaux(&self) -> usize {
+        self.gabarit.nombre_radicaux()
+    }
+
+    // Le schème peut-il s'appliquer à une racine de cette longueur ?
+    pub fn compatible_avec(&self, racine: &Racine) -> bool {
+        self.nombre_radicaux() == racine.longueur()
+    }
 }
 
 // État d'une case dans la table de hachage :
@@ -18,42 +155,130 @@ enum Slot {
     Occupied(String, Scheme),
 }
 
+// Seuil de charge (cases occupées + tombstones) au-delà duquel la table est reconstruite
+const SEUIL_CHARGE: f64 = 0.7;
+
+// Taille maximale de la table : au-delà, try_insert refuse au lieu d'allouer
+const CAPACITE_MAX: usize = 1 << 20;
+
+// Plus petit nombre premier >= n (les tailles premières répartissent mieux le double hashing)
+fn premier_suivant(n: usize) -> usize {
+    let est_premier = |k: usize| {
+        k >= 2
+            && (2..)
+                .take_while(|d| d * d <= k)
+                .all(|d| !k.is_multiple_of(d))
+    };
+    let mut k = n.max(3);
+    while !est_premier(k) {
+        k += 1;
+    }
+    k
+}
+
 // Table de hachage simplifiée avec double hashing
+// La table s'agrandit toute seule (taille première suivante) quand la charge
+// dépasse SEUIL_CHARGE ; chaque reconstruction purge aussi les tombstones
+#[derive(Clone)]
 pub struct SchemeTable {
     table: Vec<Slot>,
     size: usize,
+    nb_occupes: usize,   // cases Occupied
+    nb_supprimes: usize, // cases Deleted (tombstones)
 }
 
 impl SchemeTable {
-    // Créer une table de taille fixe
+    // Créer une table de taille initiale donnée (arrondie au nombre premier suivant)
     pub fn new(size: usize) -> Self {
+        let size = premier_suivant(size);
         SchemeTable {
             table: vec![Slot::Empty; size],
             size,
+            nb_occupes: 0,
+            nb_supprimes: 0,
         }
     }
 
+    // Nombre de schèmes stockés
+    pub fn len(&self) -> usize {
+        self.nb_occupes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nb_occupes == 0
+    }
+
+    // Nombre de cases de la table
+    pub fn capacity(&self) -> usize {
+        self.size
+    }
+
+    // Taux de remplissage, tombstones compris (ils rallongent les recherches)
+    pub fn load_factor(&self) -> f64 {
+        (self.nb_occupes + self.nb_supprimes) as f64 / self.size as f64
+    }
+
     // Fonction de hachage 1 (simple et efficace)
     fn hash1(&self, key: &str) -> usize {
-        let mut hash = 0;
+        let mut hash: usize = 0;
         for ch in key.chars() {
-            hash = hash * 31 + (ch as usize);
+            hash = hash.wrapping_mul(31).wrapping_add(ch as usize);
         }
         hash % self.size
     }
 
     // Fonction de hachage 2 (pour double hashing)
     fn hash2(&self, key: &str) -> usize {
-        let mut hash = 0;
+        let mut hash: usize = 0;
         for ch in key.chars() {
-            hash = hash * 37 + (ch as usize);
+            hash = hash.wrapping_mul(37).wrapping_add(ch as usize);
         }
         let step = hash % (self.size - 1);
         if step == 0 { 1 } else { step } // Ne jamais retourner 0
     }
 
-    // Insérer un schème - O(1)
-    pub fn insert(&mut self, key: String, scheme: Scheme) {
+    // Reconstruire la table avec une nouvelle taille : tous les schèmes sont
+    // réinsérés, les tombstones disparaissent
+    fn rehash(&mut self, nouvelle_taille: usize) {
+        let anciens = std::mem::replace(&mut self.table, vec![Slot::Empty; nouvelle_taille]);
+        self.size = nouvelle_taille;
+        self.nb_occupes = 0;
+        self.nb_supprimes = 0;
+
+        for slot in anciens {
+            if let Slot::Occupied(key, scheme) = slot {
+                self.placer(key, scheme);
+            }
+        }
+    }
+
+    // Préparer une insertion : grandir ou purger si la charge dépasse le seuil
+    fn preparer_insertion(&mut self) -> Result<(), MorphoError> {
+        let charge = (self.nb_occupes + self.nb_supprimes + 1) as f64 / self.size as f64;
+        if charge <= SEUIL_CHARGE {
+            return Ok(());
+        }
+
+        // Beaucoup de tombstones : une purge à taille égale suffit
+        if ((self.nb_occupes + 1) as f64) < SEUIL_CHARGE * self.size as f64 / 2.0 {
+            self.rehash(self.size);
+            return Ok(());
+        }
+
+        // Sinon on double (au nombre premier suivant)
+        let nouvelle_taille = premier_suivant(self.size * 2);
+        if nouvelle_taille > CAPACITE_MAX {
+            return Err(MorphoError::TablePleine {
+                capacite: self.size,
+            });
+        }
+        self.rehash(nouvelle_taille);
+        Ok(())
+    }
+
+    // Placer un schème absent de la table (il doit rester au moins une case
+    // libre ou réutilisable) ; retourne false seulement si aucune case n'a été trouvée
+    fn placer(&mut self, key: String, scheme: Scheme) -> bool {
         let mut index = self.hash1(&key);
         let step = self.hash2(&key);
         let mut first_deleted: Option<usize> = None; // retenir le 1er tombstone
@@ -63,9 +288,16 @@ impl SchemeTable {
             match &self.table[index] {
                 Slot::Empty => {
                     // Case vide : insérer au tombstone si trouvé, sinon ici
-                    let pos = first_deleted.unwrap_or(index);
+                    let pos = match first_deleted {
+                        Some(pos) => {
+                            self.nb_supprimes -= 1;
+                            pos
+                        }
+                        None => index,
+                    };
                     self.table[pos] = Slot::Occupied(key, scheme);
-                    return;
+                    self.nb_occupes += 1;
+                    return true;
                 }
                 Slot::Deleted => {
                     // Retenir la première case supprimée (réutilisable)
@@ -74,12 +306,7 @@ impl SchemeTable {
                     }
                     index = (index + step) % self.size;
                 }
-                Slot::Occupied(existing_key, _) if existing_key == &key => {
-                    // Clé existe déjà : mettre à jour
-                    self.table[index] = Slot::Occupied(key, scheme);
-                    return;
-                }
-                _ => {
+                Slot::Occupied(..) => {
                     // Collision : essayer la prochaine position
                     index = (index + step) % self.size;
                 }
@@ -89,37 +316,111 @@ impl SchemeTable {
         // Si on a trouvé un tombstone pendant le parcours, on l'utilise
         if let Some(pos) = first_deleted {
             self.table[pos] = Slot::Occupied(key, scheme);
-            return;
+            self.nb_supprimes -= 1;
+            self.nb_occupes += 1;
+            return true;
         }
 
-        panic!("Table pleine ! Augmentez la taille.");
+        false
     }
 
-    // Rechercher un schème - O(1)
-    pub fn get(&self, key: &str) -> Option<&Scheme> {
+    // Insérer un nouveau schème sans jamais paniquer - O(1) amorti
+    // La table grandit automatiquement ; TablePleine au-delà de CAPACITE_MAX
+    // SchemeExistant si la clé est déjà prise : rien n'est écrasé (voir `remplacer`)
+    // La clé devient l'identifiant du schème (`scheme.id`)
+    pub fn try_insert(&mut self, key: String, mut scheme: Scheme) -> Result<(), MorphoError> {
+        if self.contains(&key) {
+            return Err(MorphoError::SchemeExistant(key));
+        }
+        scheme.id = key.clone();
+        self.preparer_insertion()?;
+        if self.placer(key, scheme) {
+            Ok(())
+        } else {
+            Err(MorphoError::TablePleine {
+                capacite: self.size,
+            })
+        }
+    }
+
+    // Remplacer le schème d'une clé existante (modification) - O(1)
+    // SchemeIntrouvable si la clé n'est pas dans la table
+    pub fn remplacer(&mut self, key: &str, mut scheme: Scheme) -> Result<(), MorphoError> {
+        let index = self
+            .position(key)
+            .ok_or_else(|| MorphoError::SchemeIntrouvable(key.to_string()))?;
+        if let Slot::Occupied(existing_key, ancien) = &mut self.table[index] {
+            scheme.id = existing_key.clone();
+            *ancien = scheme;
+        }
+        Ok(())
+    }
+
+    // Case occupée par une clé - O(1)
+    // Les identifiants sont comparés tels quels : أفعال (pluriel) et إفعال
+    // (masdar IV) sont deux clés distinctes. La forme repliée (افعل trouve
+    // أفعل) ne sert qu'à la recherche par modèle (`par_modele`, `trouver`)
+    fn position(&self, key: &str) -> Option<usize> {
         let mut index = self.hash1(key);
         let step = self.hash2(key);
 
         for _ in 0..self.size {
             match &self.table[index] {
                 Slot::Empty => return None, // Case vide : pas trouvé
-                Slot::Deleted => {
-                    // Tombstone : la clé a pu être placée plus loin, on continue
-                    index = (index + step) % self.size;
-                }
-                Slot::Occupied(existing_key, scheme) => {
-                    if existing_key == key {
-                        return Some(scheme); // Trouvé !
-                    }
-                    // Continuer la recherche
-                    index = (index + step) % self.size;
+                Slot::Occupied(existing_key, _) if existing_key == key => {
+                    return Some(index); // Trouvé !
                 }
+                // Tombstone ou autre clé : la clé a pu être placée plus loin
+                _ => index = (index + step) % self.size,
             }
         }
 
         None
     }
 
+    // Rechercher un schème - O(1)
+    pub fn get(&self, key: &str) -> Option<&Scheme> {
+        match &self.table[self.position(key)?] {
+            Slot::Occupied(_, scheme) => Some(scheme),
+            _ => None,
+        }
+    }
+
+    // Rechercher un schème, avec une erreur si la clé est inconnue
+    pub fn obtenir(&self, key: &str) -> Result<&Scheme, MorphoError> {
+        self.get(key)
+            .ok_or_else(|| MorphoError::SchemeIntrouvable(key.to_string()))
+    }
+
+    // Tous les schèmes d'un modèle (même nom), triés par identifiant
+    // par_modele("فاعل") → فاعل (participe) et فاعل_III (verbe)
+    pub fn par_modele(&self, modele: &str) -> Vec<&Scheme> {
+        let criteres = Criteres {
+            modele: Some(modele.to_string()),
+            ..Criteres::default()
+        };
+        self.filtrer(&criteres)
+            .into_iter()
+            .map(|(_, scheme)| scheme)
+            .collect()
+    }
+
+    // Schème désigné par l'utilisateur : un identifiant, sinon un modèle qui
+    // ne désigne qu'un seul schème (SchemeAmbigu s'il en désigne plusieurs)
+    pub fn trouver(&self, texte: &str) -> Result<&Scheme, MorphoError> {
+        if let Some(scheme) = self.get(texte) {
+            return Ok(scheme);
+        }
+        match self.par_modele(texte).as_slice() {
+            [] => Err(MorphoError::SchemeIntrouvable(texte.to_string())),
+            [scheme] => Ok(scheme),
+            plusieurs => Err(MorphoError::SchemeAmbigu {
+                modele: texte.to_string(),
+                ids: plusieurs.iter().map(|s| s.id.clone()).collect(),
+            }),
+        }
+    }
+
     // Vérifier si une clé existe
     pub fn contains(&self, key: &str) -> bool {
         self.get(key).is_some()
@@ -135,38 +436,62 @@ impl SchemeTable {
                 count += 1;
             }
         }
-        println!("Total: {} schèmes", count);
+        println!(
+            "Total: {} schèmes (capacité {}, charge {:.0}%)",
+            count,
+            self.size,
+            self.load_factor() * 100.0
+        );
     }
 
     // Supprimer un schème par sa clé - O(1)
     // Utilise un marqueur tombstone (Deleted) pour ne pas casser les chaînes de probing
-    // Retourne true si le schème a été trouvé et supprimé, false sinon
-    pub fn delete(&mut self, key: &str) -> bool {
-        let mut index = self.hash1(key);
-        let step = self.hash2(key);
+    // Retourne SchemeIntrouvable si la clé n'est pas dans la table
+    pub fn delete(&mut self, key: &str) -> Result<(), MorphoError> {
+        let index = self
+            .position(key)
+            .ok_or_else(|| MorphoError::SchemeIntrouvable(key.to_string()))?;
+        // On marque la case comme Deleted (tombstone) au lieu de Empty,
+        // pour ne pas casser les chaînes de probing
+        self.table[index] = Slot::Deleted;
+        self.nb_occupes -= 1;
+        self.nb_supprimes += 1;
+        Ok(())
+    }
 
-        // Parcourir la table pour trouver la clé
-        for _ in 0..self.size {
-            match &self.table[index] {
-                Slot::Empty => return false, // case vide → la clé n'existe pas
-                Slot::Deleted => {
-                    // Tombstone : continuer la recherche
-                    index = (index + step) % self.size;
-                }
-                Slot::Occupied(existing_key, _) => {
-                    if existing_key == key {
-                        // Trouvé ! On marque la case comme Deleted (tombstone)
-                        // au lieu de Empty, pour ne pas casser les chaînes
-                        self.table[index] = Slot::Deleted;
-                        // Note: le println est désormais géré dans main.rs
-                        return true;
-                    }
-                    // Pas la bonne clé, continuer avec le double hashing
-                    index = (index + step) % self.size;
+    // Obtenir toutes les entrées (clé, schème) : la clé peut différer du nom (فاعل_III)
+    pub fn get_all_entries(&self) -> Vec<(&str, &Scheme)> {
+        self.table
+            .iter()
+            .filter_map(|slot| {
+                if let Slot::Occupied(key, scheme) = slot {
+                    Some((key.as_str(), scheme))
+                } else {
+                    None
                 }
-            }
-        }
-        false // pas trouvé après avoir parcouru toute la table
+            })
+            .collect()
+    }
+
+    // Entrées (clé, schème) qui satisfont les critères, triées par clé
+    pub fn filtrer(&self, criteres: &Criteres) -> Vec<(&str, &Scheme)> {
+        let mut entrees: Vec<(&str, &Scheme)> = self
+            .get_all_entries()
+            .into_iter()
+            .filter(|(_, scheme)| criteres.accepte(scheme))
+            .collect();
+        entrees.sort_by(|a, b| a.0.cmp(b.0));
+        entrees
+    }
+
+    // Pluriels brisés d'un schème singulier présents dans la table
+    // Une clé de pluriel absente (schème supprimé) est ignorée
+    pub fn pluriels_de(&self, scheme: &Scheme) -> Vec<&Scheme> {
+        scheme
+            .pluriels
+            .iter()
+            .filter_map(|cle| self.get(cle))
+            .collect()
     }
 
     // Obtenir tous les schèmes
@@ -185,151 +510,426 @@ impl SchemeTable {
 }
 
 // Initialiser avec les schèmes arabes courants
+// Les patterns sont entièrement vocalisés : les verbes à l'accompli, 3ème pers.
+// masc. sing. (كَتَبَ), les noms à la pause, sans désinence casuelle (كَاتِب)
 pub fn init_schemes() -> SchemeTable {
-    // Taille 31 (nombre premier) suffit pour ~15-20 schèmes
-    let mut table = SchemeTable::new(31);
+    // Taille initiale 61 (nombre premier) ; la table grandit si on ajoute des schèmes
+    let mut table = SchemeTable::new(61);
+    // Les clés intégrées sont toutes distinctes
+    let ajouter = |table: &mut SchemeTable, cle: String, scheme: Scheme| {
+        table
+            .try_insert(cle, scheme)
+            .expect("schème intégré en double");
+    };
 
     // Forme I - Base
-    table.insert(
+    ajouter(
+        &mut table,
         "فاعل".to_string(),
-        Scheme {
-            nom: "فاعل".to_string(),
-            pattern: "ف-ا-ع-ل".to_string(),
-            description: "participe actif".to_string(),
-        },
+        Scheme::new("فاعل", "فَ-ا-عِ-ل", "participe actif")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Participe)
+            .avec_pluriels(&["فعّال", "فعلاء", "فعول"])
+            .avec_proprietes(Proprietes::participe(1, Voix::Actif)),
     );
 
-    table.insert(
+    ajouter(
+        &mut table,
         "مفعول".to_string(),
-        Scheme {
-            nom: "مفعول".to_string(),
-            pattern: "م-ف-ع-و-ل".to_string(),
-            description: "participe passif".to_string(),
-        },
+        Scheme::new("مفعول", "مَ-فْ-عُ-و-ل", "participe passif")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Participe)
+            .avec_pluriels(&["مفاعيل"])
+            .avec_proprietes(Proprietes::participe(1, Voix::Passif)),
     );
 
-    table.insert(
+    ajouter(
+        &mut table,
         "فعل".to_string(),
-        Scheme {
-            nom: "فعل".to_string(),
-            pattern: "ف-ع-ل".to_string(),
-            description: "verbe forme I".to_string(),
-        },
+        Scheme::new("فعل", "فَ-عَ-لَ", "verbe forme I")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Verbe)
+            .avec_proprietes(Proprietes::verbe(1)),
     );
 
     // Forme II
-    table.insert(
+    ajouter(
+        &mut table,
         "فعّل".to_string(),
-        Scheme {
-            nom: "فعّل".to_string(),
-            pattern: "ف-ع-ّ-ل".to_string(),
-            description: "verbe forme II".to_string(),
-        },
+        Scheme::new("فعّل", "فَ-عَّ-لَ", "verbe forme II")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Verbe)
+            .avec_proprietes(Proprietes::verbe(2)),
     );
 
-    table.insert(
+    ajouter(
+        &mut table,
         "تفعيل".to_string(),
-        Scheme {
-            nom: "تفعيل".to_string(),
-            pattern: "ت-ف-ع-ي-ل".to_string(),
-            description: "masdar forme II".to_string(),
-        },
+        Scheme::new("تفعيل", "تَ-فْ-عِ-ي-ل", "masdar forme II")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Masdar)
+            .avec_proprietes(Proprietes::masdar(2)),
     );
 
-    table.insert(
+    ajouter(
+        &mut table,
         "مفعّل".to_string(),
-        Scheme {
-            nom: "مفعّل".to_string(),
-            pattern: "م-ف-ع-ّ-ل".to_string(),
-            description: "participe actif forme II".to_string(),
-        },
+        Scheme::new("مفعّل", "مُ-فَ-عِّ-ل", "participe actif forme II")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Participe)
+            .avec_proprietes(Proprietes::participe(2, Voix::Actif)),
     );
 
     // Forme III
-    table.insert(
+    ajouter(
+        &mut table,
         "فاعل_III".to_string(),
-        Scheme {
-            nom: "فاعل".to_string(),
-            pattern: "ف-ا-ع-ل".to_string(),
-            description: "verbe forme III".to_string(),
-        },
+        Scheme::new("فاعل", "فَ-ا-عَ-لَ", "verbe forme III")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Verbe)
+            .avec_proprietes(Proprietes::verbe(3)),
     );
 
-    table.insert(
+    ajouter(
+        &mut table,
         "مفاعلة".to_string(),
-        Scheme {
-            nom: "مفاعلة".to_string(),
-            pattern: "م-ف-ا-ع-ل-ة".to_string(),
-            description: "masdar forme III".to_string(),
-        },
+        Scheme::new("مفاعلة", "مُ-فَ-ا-عَ-لَ-ة", "masdar forme III")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Masdar)
+            .avec_proprietes(Proprietes::masdar(3)),
     );
 
     // Forme IV
-    table.insert(
+    ajouter(
+        &mut table,
         "أفعل".to_string(),
-        Scheme {
-            nom: "أفعل".to_string(),
-            pattern: "أ-ف-ع-ل".to_string(),
-            description: "verbe forme IV".to_string(),
-        },
+        Scheme::new("أفعل", "أَ-فْ-عَ-لَ", "verbe forme IV")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Verbe)
+            .avec_proprietes(Proprietes::verbe(4)),
     );
 
-    table.insert(
+    ajouter(
+        &mut table,
         "إفعال".to_string(),
-        Scheme {
-            nom: "إفعال".to_string(),
-            pattern: "إ-ف-ع-ا-ل".to_string(),
-            description: "masdar forme IV".to_string(),
-        },
+        Scheme::new("إفعال", "إِ-فْ-عَ-ا-ل", "masdar forme IV")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Masdar)
+            .avec_proprietes(Proprietes::masdar(4)),
     );
 
     // Forme V
-    table.insert(
+    ajouter(
+        &mut table,
         "تفعّل".to_string(),
-        Scheme {
-            nom: "تفعّل".to_string(),
-            pattern: "ت-ف-ع-ّ-ل".to_string(),
-            description: "verbe forme V".to_string(),
-        },
+        Scheme::new("تفعّل", "تَ-فَ-عَّ-لَ", "verbe forme V")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Verbe)
+            .avec_proprietes(Proprietes::verbe(5)),
     );
 
     // Forme VIII
-    table.insert(
+    ajouter(
+        &mut table,
         "افتعل".to_string(),
-        Scheme {
-            nom: "افتعل".to_string(),
-            pattern: "ا-ف-ت-ع-ل".to_string(),
-            description: "verbe forme VIII".to_string(),
-        },
+        Scheme::new("افتعل", "ا-فْ-تَ-عَ-لَ", "verbe forme VIII")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Verbe)
+            .avec_proprietes(Proprietes::verbe(8)),
     );
 
-    table.insert(
+    ajouter(
+        &mut table,
         "مفتعل".to_string(),
-        Scheme {
-            nom: "مفتعل".to_string(),
-            pattern: "م-ف-ت-ع-ل".to_string(),
-            description: "participe forme VIII".to_string(),
-        },
+        Scheme::new("مفتعل", "مُ-فْ-تَ-عِ-ل", "participe forme VIII")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Participe)
+            .avec_proprietes(Proprietes::participe(8, Voix::Actif)),
     );
 
     // Forme X
-    table.insert(
+    ajouter(
+        &mut table,
         "استفعل".to_string(),
-        Scheme {
-            nom: "استفعل".to_string(),
-            pattern: "ا-س-ت-ف-ع-ل".to_string(),
-            description: "verbe forme X".to_string(),
-        },
+        Scheme::new("استفعل", "ا-سْ-تَ-فْ-عَ-لَ", "verbe forme X")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Verbe)
+            .avec_proprietes(Proprietes::verbe(10)),
     );
 
-    table.insert(
+    ajouter(
+        &mut table,
         "مستفعل".to_string(),
-        Scheme {
-            nom: "مستفعل".to_string(),
-            pattern: "م-س-ت-ف-ع-ل".to_string(),
-            description: "participe forme X".to_string(),
-        },
+        Scheme::new("مستفعل", "مُ-سْ-تَ-فْ-عِ-ل", "participe forme X")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Participe)
+            .avec_proprietes(Proprietes::participe(10, Voix::Actif)),
+    );
+
+    // Quadrilitères (racines à 4 radicaux : ترجم، دحرج، زلزل)
+    // Le 1er ل correspond au 3ème radical, le 2ème ل au 4ème radical
+    ajouter(
+        &mut table,
+        "فعلل".to_string(),
+        Scheme::new("فعلل", "فَ-عْ-لَ-لَ", "verbe quadrilitère forme I")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Verbe)
+            .avec_proprietes(Proprietes::verbe(1).quadrilitere()),
+    );
+
+    ajouter(
+        &mut table,
+        "تفعلل".to_string(),
+        Scheme::new("تفعلل", "تَ-فَ-عْ-لَ-لَ", "verbe quadrilitère forme II")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Verbe)
+            .avec_proprietes(Proprietes::verbe(2).quadrilitere()),
+    );
+
+    ajouter(
+        &mut table,
+        "مفعلل".to_string(),
+        Scheme::new("مفعلل", "مُ-فَ-عْ-لِ-ل", "participe quadrilitère")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Participe)
+            .avec_proprietes(Proprietes::participe(1, Voix::Actif).quadrilitere()),
+    );
+
+    ajouter(
+        &mut table,
+        "فعللة".to_string(),
+        Scheme::new("فعللة", "فَ-عْ-لَ-لَ-ة", "masdar quadrilitère")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Masdar)
+            .avec_proprietes(Proprietes::masdar(1).quadrilitere()),
+    );
+
+    // ========================================================================
+    // Noms : les singuliers pointent vers leurs pluriels brisés (`pluriels`)
+    // ========================================================================
+
+    ajouter(
+        &mut table,
+        "فعيل".to_string(),
+        Scheme::new("فعيل", "فَ-عِ-ي-ل", "nom / adjectif (كبير، أمير)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Nom)
+            .avec_pluriels(&["فعلاء", "فعال", "أفعال"])
+            .avec_proprietes(Proprietes::adjectif(Nombre::Singulier, Genre::Masculin)),
+    );
+
+    ajouter(
+        &mut table,
+        "فاعلة".to_string(),
+        Scheme::new("فاعلة", "فَ-ا-عِ-لَ-ة", "féminin du participe actif")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Feminin)
+            .avec_pluriels(&["فواعل"])
+            .avec_proprietes(Proprietes {
+                genre: Some(Genre::Feminin),
+                ..Proprietes::participe(1, Voix::Actif)
+            }),
+    );
+
+    ajouter(
+        &mut table,
+        "فعلي".to_string(),
+        Scheme::new("فعلي", "فِ-عْ-لِ-يّ", "nom de relation, nisba (علميّ)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::Nisba)
+            .avec_proprietes(Proprietes::adjectif(Nombre::Singulier, Genre::Masculin)),
+    );
+
+    // Noms de lieu et de temps
+    ajouter(
+        &mut table,
+        "مفعل".to_string(),
+        Scheme::new("مفعل", "مَ-فْ-عَ-ل", "nom de lieu / de temps (مكتب)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::NomDeLieu)
+            .avec_pluriels(&["مفاعل"])
+            .avec_proprietes(Proprietes::nom(Nombre::Singulier, Genre::Masculin)),
+    );
+
+    ajouter(
+        &mut table,
+        "مفعلة".to_string(),
+        Scheme::new("مفعلة", "مَ-فْ-عَ-لَ-ة", "nom de lieu (مدرسة، مكتبة)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::NomDeLieu)
+            .avec_pluriels(&["مفاعل"])
+            .avec_proprietes(Proprietes::nom(Nombre::Singulier, Genre::Feminin)),
+    );
+
+    // Nom d'instrument
+    ajouter(
+        &mut table,
+        "مفعال".to_string(),
+        Scheme::new("مفعال", "مِ-فْ-عَ-ا-ل", "nom d'instrument (مفتاح)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::NomDInstrument)
+            .avec_pluriels(&["مفاعيل"])
+            .avec_proprietes(Proprietes::nom(Nombre::Singulier, Genre::Masculin)),
+    );
+
+    // ========================================================================
+    // Pluriels brisés
+    // ========================================================================
+
+    ajouter(
+        &mut table,
+        "فعول".to_string(),
+        Scheme::new("فعول", "فُ-عُ-و-ل", "pluriel brisé (قلوب، شهود)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::PlurielBrise)
+            .avec_proprietes(Proprietes::pluriel()),
+    );
+
+    ajouter(
+        &mut table,
+        "أفعال".to_string(),
+        Scheme::new("أفعال", "أَ-فْ-عَ-ا-ل", "pluriel brisé (أقلام، أشراف)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::PlurielBrise)
+            .avec_proprietes(Proprietes::pluriel()),
+    );
+
+    ajouter(
+        &mut table,
+        "فعلاء".to_string(),
+        Scheme::new("فعلاء", "فُ-عَ-لَ-ا-ء", "pluriel brisé (علماء، أمراء)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::PlurielBrise)
+            .avec_proprietes(Proprietes::pluriel()),
+    );
+
+    ajouter(
+        &mut table,
+        "فعال".to_string(),
+        Scheme::new("فعال", "فِ-عَ-ا-ل", "pluriel brisé (كبار، رجال)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::PlurielBrise)
+            .avec_proprietes(Proprietes::pluriel()),
+    );
+
+    ajouter(
+        &mut table,
+        "فعّال".to_string(),
+        Scheme::new("فعّال", "فُ-عَّ-ا-ل", "pluriel brisé (كتّاب، طلّاب)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::PlurielBrise)
+            .avec_proprietes(Proprietes::pluriel()),
+    );
+
+    ajouter(
+        &mut table,
+        "فواعل".to_string(),
+        Scheme::new("فواعل", "فَ-وَ-ا-عِ-ل", "pluriel brisé (قواعد)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::PlurielBrise)
+            .avec_proprietes(Proprietes::pluriel()),
+    );
+
+    ajouter(
+        &mut table,
+        "مفاعل".to_string(),
+        Scheme::new("مفاعل", "مَ-فَ-ا-عِ-ل", "pluriel brisé (مكاتب، مدارس)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::PlurielBrise)
+            .avec_proprietes(Proprietes::pluriel()),
+    );
+
+    ajouter(
+        &mut table,
+        "مفاعيل".to_string(),
+        Scheme::new("مفاعيل", "مَ-فَ-ا-عِ-ي-ل", "pluriel brisé (مفاتيح، مكاتيب)")
+            .expect("pattern invalide")
+            .avec_categorie(Categorie::PlurielBrise)
+            .avec_proprietes(Proprietes::pluriel()),
     );
 
     table
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheme() -> Scheme {
+        Scheme::new("فاعل", "فَ-ا-عِ-ل", "participe actif").unwrap()
+    }
+
+    // Remplir la table jusqu'au seuil de charge, sans la faire grandir
+    fn remplir(table: &mut SchemeTable) -> Vec<String> {
+        let mut cles = Vec::new();
+        while ((table.len() + 1) as f64) / (table.capacity() as f64) <= SEUIL_CHARGE {
+            let cle = format!("s{}", cles.len());
+            table.try_insert(cle.clone(), scheme()).unwrap();
+            cles.push(cle);
+        }
+        cles
+    }
+
+    #[test]
+    fn croissance_au_nombre_premier_suivant() {
+        let mut table = SchemeTable::new(11);
+        let mut cles = remplir(&mut table);
+        assert_eq!(table.capacity(), 11);
+
+        // Une insertion de plus dépasse le seuil : 11 → premier suivant 22
+        cles.push("de_trop".to_string());
+        table.try_insert("de_trop".to_string(), scheme()).unwrap();
+        assert_eq!(table.capacity(), 23);
+        assert!(table.load_factor() <= SEUIL_CHARGE);
+        assert_eq!(table.len(), cles.len());
+        for cle in &cles {
+            assert_eq!(table.get(cle).map(|s| s.id.as_str()), Some(cle.as_str()));
+        }
+    }
+
+    #[test]
+    fn reconstruction_purge_les_tombstones() {
+        let mut table = SchemeTable::new(11);
+        let cles = remplir(&mut table);
+        let avant = table.load_factor();
+        for cle in &cles[..5] {
+            table.delete(cle).unwrap();
+        }
+        // Les tombstones comptent encore dans la charge
+        assert_eq!(table.load_factor(), avant);
+
+        // Peu de schèmes et beaucoup de tombstones : l'insertion purge sans grandir
+        table
+            .try_insert("apres_purge".to_string(), scheme())
+            .unwrap();
+        assert_eq!(table.capacity(), 11);
+        let restantes = cles.len() - 5 + 1;
+        assert_eq!(table.load_factor(), restantes as f64 / 11.0);
+        assert!(cles[5..].iter().all(|c| table.contains(c)));
+        assert!(cles[..5].iter().all(|c| !table.contains(c)));
+        assert!(table.contains("apres_purge"));
+
+        // Reconstruction à taille égale : le nouveau tombstone disparaît aussi
+        table.delete("apres_purge").unwrap();
+        assert_eq!(table.load_factor(), restantes as f64 / 11.0);
+        table.rehash(table.capacity());
+        assert_eq!(table.load_factor(), (restantes - 1) as f64 / 11.0);
+        assert!(cles[5..].iter().all(|c| table.contains(c)));
+    }
+
+    #[test]
+    fn table_pleine_a_la_capacite_max() {
+        // Doubler cette table dépasserait CAPACITE_MAX
+        let mut table = SchemeTable::new(CAPACITE_MAX / 2 + 1);
+        let taille = table.capacity();
+        remplir(&mut table);
+
+        let erreur = table.try_insert("de_trop".to_string(), scheme());
+        assert!(matches!(
+            erreur,
+            Err(MorphoError::TablePleine { capacite }) if capacite == taille
+        ));
+        assert!(!table.contains("de_trop"));
+        assert_eq!(table.capacity(), taille);
+    }
+}
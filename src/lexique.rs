@@ -0,0 +1,530 @@
+// ============================================================================
+// LEXIQUE : format de sauvegarde commun au terminal et au web
+// ============================================================================
+// Un lexique contient tout l'état d'un moteur : les racines avec leurs dérivés
+// et la table des schèmes complète. Le même JSON est écrit par le terminal
+// (`--lexique fichier.json`) et lu par `importer_donnees` côté web.
+//
+//   {
+//     "version": 7,
+//     "meta": { "date_export": "2025-03-01T10:00:00Z", "version_moteur": "0.1.0" },
+//     "racines": [ { "racine": ["ك","ت","ب"], "frequence": 12,
+//                    "derives": [ { "mot": "كاتب", "schema": "فاعل", "occurrences": 7 } ] } ],
+//     "schemes": [ { "cle": "فاعل", "nom": "فاعل", "pattern": "ف-ا-ع-ل",
+//                    "description": "participe actif", "categorie": "participe",
+//                    "pluriels": ["فعّال", "فعلاء"],
+//                    "proprietes": { "forme": 1, "nature": "participe", "voix": "actif" } } ]
+//   }
+//
+// Historique des versions (toutes acceptées à l'import, voir `migrer`) :
+//   v0 : ancien export web, sans champ "version", racines AVEC dérivés seulement
+//        { "racines": [ [["ك","ت","ب"], [["كاتب","فاعل"]]] ] }
+//        pas de schèmes : la table existante est conservée à l'import
+//   v1 : racines (toutes) + fréquence + table des schèmes
+//   v2 : v1 + métadonnées "meta" (date d'export, version du moteur)
+//   v3 : v2 + catégorie, pluriels brisés et propriétés (forme, nature, voix...)
+//        de chaque schème
+//   v4 : v3 + traits fléchis facultatifs des dérivés validés avec une
+//        désinence : { "mot": "كاتبون", "schema": "فاعل",
+//                      "flexion": { "genre": "masculin", "nombre": "pluriel", "cas": "nominatif" } }
+//   v5 : v4 + fréquences tirées des textes analysés : "frequence" d'une racine
+//        (avant : nombre de dérivés, remis à 0 à la migration) et
+//        "occurrences" de chaque dérivé (0 si absent)
+//   v6 : v5, clés de schèmes comparées telles quelles : le pluriel brisé
+//        intégré "أفعال_جمع" devient "أفعال" (distinct du masdar IV إفعال)
+//   v7 : v6 + propriété "quadrilitere" des schèmes de forme quadrilitère
+//        (تفعلل est QII, pas la forme II)
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::io::Write;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+use wasm_bindgen::prelude::*;
+
+use crate::arbre::{Derive, Tree};
+use crate::erreur::MorphoError;
+use crate::hashing::{Categorie, Scheme, SchemeTable, init_schemes};
+use crate::proprietes::Proprietes;
+use crate::racine::Racine;
+
+// Version écrite par cette version du moteur
+pub const VERSION_LEXIQUE: u64 = 7;
+
+// Informations sur l'export (absentes des lexiques v0 et v1)
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Metadonnees {
+    pub date_export: String, // ISO 8601, UTC
+    pub version_moteur: String,
+}
+
+impl Metadonnees {
+    pub fn maintenant() -> Self {
+        Metadonnees {
+            date_export: date_iso_maintenant(),
+            version_moteur: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+// Une racine et ses dérivés
+// `frequence` : occurrences de la racine dans les textes analysés
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RacineLexique {
+    pub racine: Racine,
+    pub frequence: u32,
+    pub derives: Vec<Derive>,
+}
+
+// Un schème tel qu'il est stocké dans la table (la clé peut différer du nom)
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemeLexique {
+    pub cle: String,
+    pub nom: String,
+    pub pattern: String,
+    pub description: String,
+    // Absents avant v3 (voir `migrer`)
+    #[serde(default)]
+    pub categorie: Categorie,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pluriels: Vec<String>,
+    #[serde(default)]
+    pub proprietes: Proprietes,
+}
+
+impl SchemeLexique {
+    pub fn depuis(cle: &str, scheme: &Scheme) -> SchemeLexique {
+        SchemeLexique {
+            cle: cle.to_string(),
+            nom: scheme.nom.clone(),
+            pattern: scheme.pattern.clone(),
+            description: scheme.description.clone(),
+            categorie: scheme.categorie,
+            pluriels: scheme.pluriels.clone(),
+            proprietes: scheme.proprietes,
+        }
+    }
+
+    // Reconstruire le schème (le pattern est analysé à nouveau)
+    pub fn vers_scheme(&self) -> Result<Scheme, MorphoError> {
+        let pluriels: Vec<&str> = self.pluriels.iter().map(|p| p.as_str()).collect();
+        Ok(Scheme::new(&self.nom, &self.pattern, &self.description)?
+            .avec_categorie(self.categorie)
+            .avec_pluriels(&pluriels)
+            .avec_proprietes(self.proprietes))
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Lexique {
+    pub version: u64,
+    #[serde(default)]
+    pub meta: Option<Metadonnees>,
+    pub racines: Vec<RacineLexique>,
+    // None : lexique sans schèmes (ancien format), la table existante est conservée
+    pub schemes: Option<Vec<SchemeLexique>>,
+}
+
+// ============================================================================
+// FUSION (import sans écraser l'état local)
+// ============================================================================
+
+// Que faire quand un schème importé porte la même clé qu'un schème local différent
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum PolitiqueConflit {
+    GarderLocal,    // le schème local est conservé
+    PrendreImporte, // le schème importé remplace le local
+    Echouer,        // la fusion est refusée, rien n'est modifié
+}
+
+impl PolitiqueConflit {
+    // Nom utilisé en ligne de commande : local, importe, echec
+    pub fn depuis_texte(texte: &str) -> Option<Self> {
+        match texte {
+            "local" => Some(PolitiqueConflit::GarderLocal),
+            "importe" => Some(PolitiqueConflit::PrendreImporte),
+            "echec" => Some(PolitiqueConflit::Echouer),
+            _ => None,
+        }
+    }
+}
+
+// Un dérivé ajouté ou ignoré pendant la fusion
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DeriveFusion {
+    pub racine: String,
+    pub mot: String,
+    pub schema: String,
+}
+
+// Deux schèmes différents sous la même clé, et la version retenue
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ConflitScheme {
+    pub cle: String,
+    pub local: SchemeLexique,
+    pub importe: SchemeLexique,
+    pub garde_importe: bool,
+}
+
+// Compte rendu d'une fusion
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct RapportFusion {
+    pub racines_ajoutees: Vec<String>,
+    pub derives_ajoutes: Vec<DeriveFusion>,
+    pub derives_ignores: Vec<DeriveFusion>, // déjà présents sur la racine
+    pub schemes_ajoutes: Vec<String>,
+    pub schemes_ignores: Vec<String>, // identiques au schème local
+    pub conflits: Vec<ConflitScheme>,
+}
+
+// ============================================================================
+// ANCIEN FORMAT (v0)
+// ============================================================================
+
+// Une racine exportée avec ses dérivés : (racine, [(mot, schema)])
+type RacineExportee = (Racine, Vec<(String, String)>);
+
+// Structure pour exporter l'état complet de l'arbre
+#[derive(Serialize, Deserialize)]
+pub struct ExportData {
+    pub racines: Vec<RacineExportee>,
+}
+
+impl From<ExportData> for Lexique {
+    fn from(data: ExportData) -> Self {
+        let racines = data
+            .racines
+            .into_iter()
+            .map(|(racine, derives)| RacineLexique {
+                racine,
+                frequence: 0,
+                derives: derives
+                    .into_iter()
+                    .map(|(mot, schema)| Derive::new(mot, schema))
+                    .collect(),
+            })
+            .collect();
+        Lexique {
+            version: VERSION_LEXIQUE,
+            meta: None,
+            racines,
+            schemes: None,
+        }
+    }
+}
+
+impl Lexique {
+    // Photographier l'état d'un arbre et d'une table de schèmes
+    pub fn depuis(arbre: &Tree, table: &SchemeTable) -> Lexique {
+        let mut liste = Vec::new();
+        arbre.collecter_racines(&mut liste);
+        let racines = liste
+            .into_iter()
+            .map(|(racine, frequence, derives)| RacineLexique {
+                racine,
+                frequence,
+                derives,
+            })
+            .collect();
+
+        // Trier par clé : le fichier ne change pas d'une sauvegarde à l'autre
+        let mut entrees = table.get_all_entries();
+        entrees.sort_by(|a, b| a.0.cmp(b.0));
+        let schemes = entrees
+            .into_iter()
+            .map(|(cle, s)| SchemeLexique::depuis(cle, s))
+            .collect();
+
+        Lexique {
+            version: VERSION_LEXIQUE,
+            meta: Some(Metadonnees::maintenant()),
+            racines,
+            schemes: Some(schemes),
+        }
+    }
+
+    // Remplacer le contenu de l'arbre (et de la table si le lexique a des schèmes)
+    // Tout est reconstruit avant d'être remplacé : en cas d'erreur rien ne change
+    pub fn appliquer(self, arbre: &mut Tree, table: &mut SchemeTable) -> Result<(), MorphoError> {
+        let mut nouvel_arbre = Tree::new();
+        for r in self.racines {
+            nouvel_arbre.insert(r.racine);
+            let noeud = nouvel_arbre.noeud(r.racine)?;
+            noeud.frequence = r.frequence;
+            for d in r.derives {
+                noeud.ajouter(d);
+            }
+        }
+
+        if let Some(schemes) = self.schemes {
+            let mut nouvelle_table = SchemeTable::new(schemes.len() * 2);
+            for s in schemes {
+                let scheme = s.vers_scheme()?;
+                nouvelle_table.try_insert(s.cle, scheme)?;
+            }
+            *table = nouvelle_table;
+        }
+
+        *arbre = nouvel_arbre;
+        Ok(())
+    }
+
+    // Fusionner dans l'arbre et la table existants (rien n'est supprimé)
+    // Une racine ou un dérivé ajouté garde ses fréquences importées ; ceux qui
+    // existent déjà gardent leurs fréquences locales
+    // Les schèmes sont fusionnés dans une copie de la table, qui ne remplace
+    // l'originale qu'une fois tous insérés : un conflit (politique Echouer),
+    // une clé importée deux fois ou une table pleine laissent l'arbre et la
+    // table intacts
+    pub fn fusionner(
+        self,
+        arbre: &mut Tree,
+        table: &mut SchemeTable,
+        politique: PolitiqueConflit,
+    ) -> Result<RapportFusion, MorphoError> {
+        let mut rapport = RapportFusion::default();
+
+        // 1) Schèmes : classer chaque schème importé (nouveau, identique, conflit)
+        let mut a_inserer = Vec::new();
+        let mut a_remplacer = Vec::new();
+        for importe in self.schemes.unwrap_or_default() {
+            let scheme = importe.vers_scheme()?;
+            let Some(local) = table.get(&importe.cle) else {
+                rapport.schemes_ajoutes.push(importe.cle.clone());
+                a_inserer.push((importe.cle, scheme));
+                continue;
+            };
+            let local = SchemeLexique::depuis(&importe.cle, local);
+            if local == importe {
+                rapport.schemes_ignores.push(importe.cle);
+                continue;
+            }
+
+            if politique == PolitiqueConflit::Echouer {
+                return Err(MorphoError::ConflitScheme(importe.cle));
+            }
+            let garde_importe = politique == PolitiqueConflit::PrendreImporte;
+            rapport.conflits.push(ConflitScheme {
+                cle: importe.cle.clone(),
+                local,
+                importe: importe.clone(),
+                garde_importe,
+            });
+            if garde_importe {
+                a_remplacer.push((importe.cle, scheme));
+            }
+        }
+        let mut nouvelle_table = table.clone();
+        for (cle, scheme) in a_inserer {
+            nouvelle_table.try_insert(cle, scheme)?;
+        }
+        for (cle, scheme) in a_remplacer {
+            nouvelle_table.remplacer(&cle, scheme)?;
+        }
+        *table = nouvelle_table;
+
+        // 2) Racines et dérivés : on ajoute ce qui manque
+        for r in self.racines {
+            let texte = r.racine.to_string();
+            let nouvelle = arbre.insert(r.racine);
+            if nouvelle {
+                rapport.racines_ajoutees.push(texte.clone());
+            }
+            let noeud = arbre.noeud(r.racine)?;
+            if nouvelle {
+                noeud.frequence = r.frequence;
+            }
+            for d in r.derives {
+                let fusion = DeriveFusion {
+                    racine: texte.clone(),
+                    mot: d.mot.clone(),
+                    schema: d.schema.clone(),
+                };
+                if noeud.ajouter(d) {
+                    rapport.derives_ajoutes.push(fusion);
+                } else {
+                    rapport.derives_ignores.push(fusion);
+                }
+            }
+        }
+
+        Ok(rapport)
+    }
+
+    // ========================================================================
+    // JSON
+    // ========================================================================
+
+    pub fn vers_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    // JSON compact (localStorage)
+    pub fn vers_json_compact(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    // Lire un lexique de n'importe quelle version (migré vers la version actuelle)
+    pub fn depuis_json(json: &str) -> Result<Lexique, MorphoError> {
+        let valeur: Value =
+            serde_json::from_str(json).map_err(|e| MorphoError::Json(e.to_string()))?;
+        Lexique::migrer(valeur)
+    }
+
+    // Migration des anciens formats vers VERSION_LEXIQUE
+    //   v0 → racines converties, pas de schèmes (table conservée), pas de meta
+    //   v1 → même structure, sans meta
+    //   v1, v2 → catégorie, pluriels et propriétés repris du schème intégré de
+    //            même clé et de même pattern (init_schemes), vides sinon
+    //   v1 à v4 → "frequence" comptait les dérivés : remise à 0
+    //   v1 à v5 → clé "أفعال_جمع" renommée "أفعال" (schème, pluriels, dérivés),
+    //             sauf si le lexique a déjà un schème "أفعال"
+    //   v1 à v6 → schèmes à 4 radicaux qui ont une forme : marqués quadrilitères
+    pub fn migrer(valeur: Value) -> Result<Lexique, MorphoError> {
+        let erreur_json = |e: serde_json::Error| MorphoError::Json(e.to_string());
+
+        let mut lexique: Lexique = match valeur.get("version").map(|v| v.as_u64()) {
+            None => {
+                let data: ExportData = serde_json::from_value(valeur).map_err(erreur_json)?;
+                data.into()
+            }
+            Some(Some(1..=VERSION_LEXIQUE)) => {
+                serde_json::from_value(valeur).map_err(erreur_json)?
+            }
+            Some(version) => {
+                return Err(MorphoError::VersionLexique(
+                    version.map(|v| v.to_string()).unwrap_or_default(),
+                ));
+            }
+        };
+        if lexique.version < 3
+            && let Some(schemes) = lexique.schemes.as_mut()
+        {
+            let integres = init_schemes();
+            for s in schemes.iter_mut() {
+                if let Some(integre) = integres.get(&s.cle)
+                    && integre.pattern == s.pattern
+                {
+                    s.categorie = integre.categorie;
+                    s.pluriels = integre.pluriels.clone();
+                    s.proprietes = integre.proprietes;
+                }
+            }
+        }
+        if lexique.version < 5 {
+            for r in lexique.racines.iter_mut() {
+                r.frequence = 0;
+            }
+        }
+        if lexique.version < 6 {
+            lexique.renommer_scheme("أفعال_جمع", "أفعال");
+        }
+        if lexique.version < 7
+            && let Some(schemes) = lexique.schemes.as_mut()
+        {
+            for s in schemes.iter_mut().filter(|s| s.proprietes.forme.is_some()) {
+                s.proprietes.quadrilitere = s
+                    .vers_scheme()
+                    .is_ok_and(|scheme| scheme.nombre_radicaux() == 4);
+            }
+        }
+        lexique.version = VERSION_LEXIQUE;
+        Ok(lexique)
+    }
+
+    // Renommer une clé de schème partout où elle apparaît (si la nouvelle
+    // clé n'est pas déjà prise)
+    fn renommer_scheme(&mut self, ancienne: &str, nouvelle: &str) {
+        if let Some(schemes) = self.schemes.as_mut() {
+            if schemes.iter().any(|s| s.cle == nouvelle) {
+                return;
+            }
+            for s in schemes.iter_mut() {
+                if s.cle == ancienne {
+                    s.cle = nouvelle.to_string();
+                }
+                for p in s.pluriels.iter_mut().filter(|p| *p == ancienne) {
+                    *p = nouvelle.to_string();
+                }
+            }
+        }
+        for d in self.racines.iter_mut().flat_map(|r| r.derives.iter_mut()) {
+            if d.schema == ancienne {
+                d.schema = nouvelle.to_string();
+            }
+        }
+    }
+
+    // ========================================================================
+    // FICHIER
+    // ========================================================================
+
+    pub fn lire_fichier(chemin: &str) -> Result<Lexique, MorphoError> {
+        let texte = fs::read_to_string(chemin).map_err(|e| erreur_fichier(chemin, e))?;
+        Lexique::depuis_json(&texte)
+    }
+
+    // Écriture atomique : on écrit dans "chemin.tmp" puis on renomme,
+    // un arrêt brutal ne laisse jamais un lexique à moitié écrit
+    pub fn ecrire_fichier(&self, chemin: &str) -> Result<(), MorphoError> {
+        let temporaire = format!("{}.tmp", chemin);
+        let ecrire = || -> std::io::Result<()> {
+            let mut fichier = fs::File::create(&temporaire)?;
+            fichier.write_all(self.vers_json().as_bytes())?;
+            fichier.sync_all()?;
+            fs::rename(&temporaire, chemin)
+        };
+
+        ecrire().map_err(|e| {
+            let _ = fs::remove_file(&temporaire);
+            erreur_fichier(chemin, e)
+        })
+    }
+}
+
+fn erreur_fichier(chemin: &str, e: std::io::Error) -> MorphoError {
+    MorphoError::Fichier {
+        chemin: chemin.to_string(),
+        message: e.to_string(),
+    }
+}
+
+// Date courante au format ISO 8601 (UTC)
+// En WebAssembly l'horloge système n'existe pas : on passe par Date de JavaScript
+#[cfg(target_arch = "wasm32")]
+fn date_iso_maintenant() -> String {
+    String::from(js_sys::Date::new_0().to_iso_string())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn date_iso_maintenant() -> String {
+    let secondes = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    // Jours depuis 1970 → (année, mois, jour) (algorithme "civil from days")
+    let jours = (secondes / 86_400) as i64;
+    let z = jours + 719_468;
+    let ere = z.div_euclid(146_097);
+    let jour_ere = z.rem_euclid(146_097);
+    let annee_ere = (jour_ere - jour_ere / 1460 + jour_ere / 36_524 - jour_ere / 146_096) / 365;
+    let jour_annee = jour_ere - (365 * annee_ere + annee_ere / 4 - annee_ere / 100);
+    let m = (5 * jour_annee + 2) / 153;
+    let jour = jour_annee - (153 * m + 2) / 5 + 1;
+    let mois = if m < 10 { m + 3 } else { m - 9 };
+    let annee = annee_ere + ere * 400 + if mois <= 2 { 1 } else { 0 };
+
+    let s = secondes % 86_400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        annee,
+        mois,
+        jour,
+        s / 3600,
+        (s % 3600) / 60,
+        s % 60
+    )
+}
@@ -2,29 +2,79 @@
 // API WebAssembly pour Angular
 // Ce fichier expose les fonctions Rust au JavaScript/TypeScript
 // ============================================================================
-
-use serde::{Deserialize, Serialize};
+// L'API principale est l'objet `MoteurMorphologique` (voir moteur.rs).
+// Les fonctions libres ci-dessous sont conservées pour le code existant :
+// elles délèguent toutes au moteur par défaut créé par `init_app`.
+//
+// Les erreurs (`MorphoError`) sont levées en JavaScript comme des `Error`
+// portant un champ `code` :
+//
+//   try { supprimer_racine('ك', 'ت', 'ب'); }
+//   catch (e) { if (e.code === 'RACINE_INTROUVABLE') { ... } }
+
+use std::cell::RefCell;
 use wasm_bindgen::prelude::*;
 
-mod arbre;
-mod hashing;
-mod morpho_analyzer;
+pub mod arbre;
+pub mod conjugaison;
+pub mod erreur;
+pub mod flexion;
+pub mod gabarit;
+pub mod hashing;
+pub mod lexique;
+pub mod morpho_analyzer;
+pub mod moteur;
+pub mod normalisation;
+pub mod phonologie;
+pub mod proprietes;
+pub mod racine;
+pub mod requete;
+pub mod segmentation;
+pub mod texte;
+
+pub use conjugaison::{Forme, TableConjugaison};
+pub use erreur::MorphoError;
+pub use flexion::Flexion;
+pub use hashing::Categorie;
+pub use lexique::{ExportData, Lexique, PolitiqueConflit, RapportFusion};
+pub use morpho_analyzer::{Lecture, MembreFamille, Validation, ValidationSegmentee};
+pub use moteur::{
+    AnalyseResult, Derive, DeriveFrequent, DeriveInfo, MoteurMorphologique, OptionsScheme,
+    PageRacinesInfo, RacineInfo, SchemeInfo, ValidationResult,
+};
+pub use normalisation::Normalisation;
+pub use proprietes::{Criteres, Proprietes};
+pub use requete::{PageRacines, Pagination};
+pub use segmentation::Segmentation;
+pub use texte::AnalyseTexte;
+
+// Moteur par défaut (WebAssembly est mono-thread : un seul moteur par page)
+thread_local! {
+    static MOTEUR: RefCell<Option<MoteurMorphologique>> = const { RefCell::new(None) };
+}
 
-use arbre::Tree;
-use hashing::{SchemeTable, init_schemes};
-use morpho_analyzer::{generer_mot, valider_mot};
+// Exécuter `f` sur le moteur par défaut (erreur NonInitialise avant init_app)
+fn avec_moteur<R>(f: impl FnOnce(&mut MoteurMorphologique) -> R) -> Result<R, MorphoError> {
+    MOTEUR.with(|m| match m.borrow_mut().as_mut() {
+        Some(moteur) => Ok(f(moteur)),
+        None => Err(MorphoError::NonInitialise),
+    })
+}
 
-// État global de l'application (partagé entre les appels JS)
-static mut ARBRE: Option<Tree> = None;
-static mut SCHEMES: Option<SchemeTable> = None;
+// Exception JavaScript : Error { name: "MorphoError", code, message }
+impl From<MorphoError> for JsValue {
+    fn from(e: MorphoError) -> Self {
+        let erreur = js_sys::Error::new(&e.to_string());
+        erreur.set_name("MorphoError");
+        let _ = js_sys::Reflect::set(&erreur, &"code".into(), &e.code().into());
+        erreur.into()
+    }
+}
 
 // Initialiser l'application (à appeler au démarrage Angular)
 #[wasm_bindgen]
 pub fn init_app() {
-    unsafe {
-        ARBRE = Some(Tree::new());
-        SCHEMES = Some(init_schemes());
-    }
+    MOTEUR.with(|m| *m.borrow_mut() = Some(MoteurMorphologique::new()));
 }
 
 // ============================================================================
@@ -32,405 +82,339 @@ pub fn init_app() {
 // ============================================================================
 
 #[wasm_bindgen]
-pub fn ajouter_racine(c1: char, c2: char, c3: char) -> String {
-    let racine = [c1, c2, c3];
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            arbre.insert(racine);
-            let r: String = racine.iter().collect();
-            format!("✓ Racine '{}' ajoutée", r)
-        } else {
-            "❌ Erreur: Application non initialisée".to_string()
-        }
-    }
+pub fn ajouter_racine(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<String, MorphoError> {
+    avec_moteur(|m| m.ajouter_racine(c1, c2, c3, c4))?
 }
 
 #[wasm_bindgen]
-pub fn chercher_racine(c1: char, c2: char, c3: char) -> bool {
-    let racine = [c1, c2, c3];
-    unsafe {
-        if let Some(ref arbre) = ARBRE {
-            arbre.verify(racine)
-        } else {
-            false
-        }
-    }
+pub fn chercher_racine(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<bool, MorphoError> {
+    avec_moteur(|m| m.chercher_racine(c1, c2, c3, c4))?
 }
 
 #[wasm_bindgen]
-pub fn supprimer_racine(c1: char, c2: char, c3: char) -> bool {
-    let racine = [c1, c2, c3];
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            arbre.delete(racine)
-        } else {
-            false
-        }
-    }
+pub fn supprimer_racine(c1: char, c2: char, c3: char, c4: Option<char>) -> Result<(), MorphoError> {
+    avec_moteur(|m| m.supprimer_racine(c1, c2, c3, c4))?
 }
 
 #[wasm_bindgen]
-pub fn charger_racines_depuis_texte(contenu: &str) -> u32 {
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            let mut compteur = 0;
-            for ligne in contenu.lines() {
-                let chars: Vec<char> = ligne.chars().filter(|c| !c.is_whitespace()).collect();
-                if chars.len() == 3 {
-                    let racine = [chars[0], chars[1], chars[2]];
-                    arbre.insert(racine);
-                    compteur += 1;
-                }
-            }
-            compteur
-        } else {
-            0
-        }
-    }
+pub fn charger_racines_depuis_texte(contenu: &str) -> Result<u32, MorphoError> {
+    avec_moteur(|m| m.charger_racines_depuis_texte(contenu))?
 }
 
 // ============================================================================
 // GÉNÉRATION DE DÉRIVÉS
 // ============================================================================
 
-#[derive(Serialize, Deserialize)]
-pub struct Derive {
-    pub mot: String,
-    pub schema: String,
+#[wasm_bindgen]
+pub fn generer_derive(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+    schema: &str,
+) -> Result<String, MorphoError> {
+    avec_moteur(|m| m.generer_derive(c1, c2, c3, c4, schema))?
 }
 
+// Classe morphologique de la racine (صحيح، مثال، أجوف، ناقص، لفيف، مضعف، مهموز)
 #[wasm_bindgen]
-pub fn generer_derive(c1: char, c2: char, c3: char, schema: &str) -> String {
-    let racine = [c1, c2, c3];
-    generer_mot(racine, schema)
+pub fn classe_racine(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<String, MorphoError> {
+    // Ne dépend d'aucun lexique : pas besoin que l'application soit initialisée
+    let racine = racine::Racine::depuis_radicaux(c1, c2, c3, c4)?;
+    Ok(phonologie::classifier(&racine).nom_arabe().to_string())
 }
 
+// Forme nue d'un mot vocalisé, pour l'affichage (كَاتِب → كاتب)
 #[wasm_bindgen]
-pub fn generer_tous_derives(c1: char, c2: char, c3: char) -> JsValue {
-    let racine = [c1, c2, c3];
-    let mut resultats = Vec::new();
+pub fn sans_diacritiques(mot: &str) -> String {
+    gabarit::sans_diacritiques(mot)
+}
 
-    unsafe {
-        if let Some(ref schemes) = SCHEMES {
-            for scheme in schemes.get_all_schemes() {
-                let mot = generer_mot(racine, &scheme.nom);
-                resultats.push(Derive {
-                    mot,
-                    schema: scheme.nom.clone(),
-                });
-            }
-        }
-    }
+#[wasm_bindgen]
+pub fn generer_tous_derives(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.generer_tous_derives(c1, c2, c3, c4))?
+}
 
-    serde_wasm_bindgen::to_value(&resultats).unwrap()
+// Famille regroupée par catégorie, avec les paires singulier / pluriel brisé
+#[wasm_bindgen]
+pub fn generer_famille(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.generer_famille(c1, c2, c3, c4))?
 }
 
 #[wasm_bindgen]
-pub fn ajouter_derive_a_racine(c1: char, c2: char, c3: char, mot: &str, schema: &str) -> bool {
-    let racine = [c1, c2, c3];
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            // Vérifier que la racine existe, sinon refuser
-            if !arbre.verify(racine) {
-                return false;
-            }
-            arbre.ajouter_derive(racine, mot.to_string(), schema.to_string())
-        } else {
-            false
-        }
-    }
+pub fn ajouter_derive_a_racine(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+    mot: &str,
+    schema: &str,
+) -> Result<(), MorphoError> {
+    avec_moteur(|m| m.ajouter_derive_a_racine(c1, c2, c3, c4, mot, schema))?
 }
 
 // Générer un dérivé ET le stocker automatiquement (comme le terminal)
 #[wasm_bindgen]
-pub fn generer_et_stocker_derive(c1: char, c2: char, c3: char, schema: &str) -> bool {
-    let racine = [c1, c2, c3];
-
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            // Vérifier que la racine existe, sinon refuser
-            if !arbre.verify(racine) {
-                return false;
-            }
-
-            // Générer le mot
-            let mot = generer_mot(racine, schema);
-
-            // Stocker dans l'arbre
-            return arbre.ajouter_derive(racine, mot, schema.to_string());
-        }
-    }
-    false
+pub fn generer_et_stocker_derive(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+    schema: &str,
+) -> Result<String, MorphoError> {
+    avec_moteur(|m| m.generer_et_stocker_derive(c1, c2, c3, c4, schema))?
 }
 
 // Générer TOUS les dérivés ET les stocker automatiquement (comme le terminal)
 #[wasm_bindgen]
-pub fn generer_et_stocker_tous_derives(c1: char, c2: char, c3: char) -> u32 {
-    let racine = [c1, c2, c3];
-    let mut compteur = 0;
-
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            // Vérifier que la racine existe, sinon refuser
-            if !arbre.verify(racine) {
-                return 0;
-            }
-
-            if let Some(ref schemes) = SCHEMES {
-                for scheme in schemes.get_all_schemes() {
-                    let mot = generer_mot(racine, &scheme.nom);
-                    if arbre.ajouter_derive(racine, mot, scheme.nom.clone()) {
-                        compteur += 1;
-                    }
-                }
-            }
-        }
-    }
+pub fn generer_et_stocker_tous_derives(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<u32, MorphoError> {
+    avec_moteur(|m| m.generer_et_stocker_tous_derives(c1, c2, c3, c4))?
+}
+
+// ============================================================================
+// CONJUGAISON
+// ============================================================================
 
-    compteur
+// Tableau de conjugaison d'une racine pour une forme ("I".."X", "QI", "QII",
+// modèle ou clé de schème) ; `voyelles` : باب de la forme I ("a-u"...)
+#[wasm_bindgen]
+pub fn conjuguer(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+    forme: &str,
+    voyelles: Option<String>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.conjuguer(c1, c2, c3, c4, forme, voyelles))?
 }
 
 // ============================================================================
 // VALIDATION DE MOTS
 // ============================================================================
 
-#[derive(Serialize, Deserialize)]
-pub struct ValidationResult {
-    pub valide: bool,
-    pub schema: Option<String>,
-}
-
-#[wasm_bindgen]
-pub fn valider_mot_derive(mot: &str, c1: char, c2: char, c3: char) -> JsValue {
-    let racine = [c1, c2, c3];
-
-    unsafe {
-        if let Some(ref schemes) = SCHEMES {
-            let (valide, schema_opt) = valider_mot(mot, racine, schemes);
-            let result = ValidationResult {
-                valide,
-                schema: schema_opt,
-            };
-            serde_wasm_bindgen::to_value(&result).unwrap()
-        } else {
-            serde_wasm_bindgen::to_value(&ValidationResult {
-                valide: false,
-                schema: None,
-            })
-            .unwrap()
-        }
-    }
+#[wasm_bindgen]
+pub fn valider_mot_derive(
+    mot: &str,
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.valider_mot_derive(mot, c1, c2, c3, c4))?
+}
+
+// Toutes les lectures du mot, avec métadonnées et drapeau d'ambiguïté
+#[wasm_bindgen]
+pub fn valider_mot_complet(
+    mot: &str,
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.valider_mot_complet(mot, c1, c2, c3, c4))?
+}
+
+// Valider un mot de texte (والمكتوب) : chaque découpe dont la base est reconnue
+#[wasm_bindgen]
+pub fn valider_mot_segmente(
+    mot: &str,
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.valider_mot_segmente(mot, c1, c2, c3, c4))?
 }
 
 // ============================================================================
-// AFFICHAGE DES DÉRIVÉS STOCKÉS
+// ANALYSE INVERSE (mot seul → racine + schème)
 // ============================================================================
 
+// Retourne toutes les hypothèses (racine, schème), racines de l'arbre en premier
 #[wasm_bindgen]
-pub fn obtenir_derives_stockes(c1: char, c2: char, c3: char) -> JsValue {
-    let racine = [c1, c2, c3];
-
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            if let Some(noeud) = arbre.chercher_noeud(racine) {
-                let derives: Vec<Derive> = noeud
-                    .derives
-                    .iter()
-                    .map(|d| Derive {
-                        mot: d.mot.clone(),
-                        schema: d.schema.clone(),
-                    })
-                    .collect();
-                return serde_wasm_bindgen::to_value(&derives).unwrap();
-            }
-        }
-    }
+pub fn analyser_mot_derive(mot: &str) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.analyser_mot_derive(mot))?
+}
 
-    serde_wasm_bindgen::to_value(&Vec::<Derive>::new()).unwrap()
+// Comme analyser_mot_derive, sur chaque découpe du mot en clitiques et base
+#[wasm_bindgen]
+pub fn analyser_mot_segmente(mot: &str) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.analyser_mot_segmente(mot))?
 }
 
+// Toutes les découpes plausibles du mot (proclitiques, base, enclitique)
 #[wasm_bindgen]
-pub fn supprimer_derive(c1: char, c2: char, c3: char, mot: &str) -> bool {
-    let racine = [c1, c2, c3];
+pub fn segmenter_mot(mot: &str) -> Result<JsValue, MorphoError> {
+    Ok(serde_wasm_bindgen::to_value(&segmentation::segmenter(mot))?)
+}
 
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            if let Some(noeud) = arbre.chercher_noeud(racine) {
-                return noeud.supprimer_derive(mot);
-            }
-        }
-    }
-    false
+// Analyse mot à mot d'un texte collé, avec couverture et fréquences
+// (les fréquences de l'arbre sont mises à jour)
+#[wasm_bindgen]
+pub fn analyser_texte(texte: &str, top: Option<usize>) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.analyser_texte(texte, top))?
 }
 
 // ============================================================================
-// GESTION DES SCHÈMES
+// AFFICHAGE DES DÉRIVÉS STOCKÉS
 // ============================================================================
 
-#[derive(Serialize, Deserialize)]
-pub struct SchemeInfo {
-    pub nom: String,
-    pub description: String,
-}
-
-#[wasm_bindgen]
-pub fn obtenir_tous_schemes() -> JsValue {
-    unsafe {
-        if let Some(ref schemes) = SCHEMES {
-            let infos: Vec<SchemeInfo> = schemes
-                .get_all_schemes()
-                .iter()
-                .map(|s| SchemeInfo {
-                    nom: s.nom.clone(),
-                    description: s.description.clone(),
-                })
-                .collect();
-            return serde_wasm_bindgen::to_value(&infos).unwrap();
-        }
-    }
-    serde_wasm_bindgen::to_value(&Vec::<SchemeInfo>::new()).unwrap()
-}
-
-#[wasm_bindgen]
-pub fn ajouter_scheme(nom: &str, pattern: &str, description: &str) -> bool {
-    unsafe {
-        if let Some(ref mut schemes) = SCHEMES {
-            use hashing::Scheme;
-            schemes.insert(
-                nom.to_string(),
-                Scheme {
-                    nom: nom.to_string(),
-                    pattern: pattern.to_string(),
-                    description: description.to_string(),
-                },
-            );
-            return true;
-        }
-    }
-    false
+#[wasm_bindgen]
+pub fn obtenir_derives_stockes(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.obtenir_derives_stockes(c1, c2, c3, c4))?
 }
 
 #[wasm_bindgen]
-pub fn supprimer_scheme(nom: &str) -> bool {
-    unsafe {
-        if let Some(ref mut schemes) = SCHEMES {
-            return schemes.delete(nom);
-        }
-    }
-    false
+pub fn supprimer_derive(
+    c1: char,
+    c2: char,
+    c3: char,
+    c4: Option<char>,
+    mot: &str,
+) -> Result<(), MorphoError> {
+    avec_moteur(|m| m.supprimer_derive(c1, c2, c3, c4, mot))?
 }
 
 // ============================================================================
-// AFFICHAGE DE L'ARBRE
+// GESTION DES SCHÈMES
 // ============================================================================
 
-#[derive(Serialize, Deserialize)]
-pub struct DeriveInfo {
-    pub mot: String,
-    pub schema: String,
+#[wasm_bindgen]
+pub fn obtenir_tous_schemes() -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.obtenir_tous_schemes())?
 }
 
-#[derive(Serialize, Deserialize)]
-pub struct RacineInfo {
-    pub racine: String,
-    pub nombre_derives: u32,
-    pub derives: Vec<DeriveInfo>,
+// Schèmes filtrés par forme, nature, voix, catégorie... (voir proprietes.rs)
+#[wasm_bindgen]
+pub fn filtrer_schemes(criteres: JsValue) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.filtrer_schemes(criteres))?
 }
 
+// `options` : { id, categorie, pluriels, proprietes }, tous facultatifs ;
+// retourne l'identifiant du schème ajouté
+// Erreur si le pattern est mal formé (voir gabarit.rs pour la syntaxe), si
+// l'identifiant est déjà pris ou si la table des schèmes ne peut plus grandir
 #[wasm_bindgen]
-pub fn obtenir_toutes_racines() -> JsValue {
-    let mut racines = Vec::new();
-
-    unsafe {
-        if let Some(ref arbre) = ARBRE {
-            arbre.collecter_racines(&mut racines);
-        }
-    }
+pub fn ajouter_scheme(
+    nom: &str,
+    pattern: &str,
+    description: &str,
+    options: JsValue,
+) -> Result<String, MorphoError> {
+    avec_moteur(|m| m.ajouter_scheme(nom, pattern, description, options))?
+}
 
-    let infos: Vec<RacineInfo> = racines
-        .iter()
-        .map(|(racine, freq, derives)| {
-            let r: String = racine.iter().collect();
-            RacineInfo {
-                racine: r,
-                nombre_derives: *freq,
-                derives: derives
-                    .iter()
-                    .map(|d| DeriveInfo {
-                        mot: d.mot.clone(),
-                        schema: d.schema.clone(),
-                    })
-                    .collect(),
-            }
-        })
-        .collect();
-
-    serde_wasm_bindgen::to_value(&infos).unwrap()
+#[wasm_bindgen]
+pub fn supprimer_scheme(nom: &str) -> Result<(), MorphoError> {
+    avec_moteur(|m| m.supprimer_scheme(nom))?
 }
 
 // ============================================================================
-// SAUVEGARDE ET RESTAURATION (pour éviter la perte lors du rafraîchissement)
+// AFFICHAGE DE L'ARBRE
 // ============================================================================
 
-// Structure pour exporter l'état complet de l'arbre
-#[derive(Serialize, Deserialize)]
-pub struct ExportData {
-    pub racines: Vec<([char; 3], Vec<(String, String)>)>, // (racine, [(mot, schema)])
+#[wasm_bindgen]
+pub fn obtenir_toutes_racines() -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.obtenir_toutes_racines())?
 }
 
-// Exporter toutes les données en JSON (pour localStorage)
+// Recherches ordonnées dans l'arbre (voir requete.rs), paginées :
+// `decalage` racines sautées (0 par défaut), `limite` racines rendues (50)
 #[wasm_bindgen]
-pub fn exporter_donnees() -> String {
-    let mut data = ExportData {
-        racines: Vec::new(),
-    };
-
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            let mut racines_list = Vec::new();
-            arbre.collecter_racines(&mut racines_list);
-
-            for (racine, _freq, derives_vec) in racines_list {
-                let derives: Vec<(String, String)> = derives_vec
-                    .iter()
-                    .map(|d| (d.mot.clone(), d.schema.clone()))
-                    .collect();
-
-                if !derives.is_empty() {
-                    data.racines.push((racine, derives));
-                }
-            }
-        }
-    }
+pub fn racines_entre(
+    debut: Option<String>,
+    fin: Option<String>,
+    decalage: Option<usize>,
+    limite: Option<usize>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.racines_entre(debut, fin, decalage, limite))?
+}
 
-    serde_json::to_string(&data).unwrap_or_else(|_| "{}".to_string())
+#[wasm_bindgen]
+pub fn racines_prefixe(
+    prefixe: &str,
+    decalage: Option<usize>,
+    limite: Option<usize>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.racines_prefixe(prefixe, decalage, limite))?
 }
 
-// Importer les données depuis JSON (depuis localStorage)
 #[wasm_bindgen]
-pub fn importer_donnees(json: &str) -> bool {
-    let data: ExportData = match serde_json::from_str(json) {
-        Ok(d) => d,
-        Err(_) => return false,
-    };
+pub fn racines_motif(
+    motif: &str,
+    decalage: Option<usize>,
+    limite: Option<usize>,
+) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.racines_motif(motif, decalage, limite))?
+}
+
+// Racines et dérivés stockés les plus fréquents dans les textes analysés
+#[wasm_bindgen]
+pub fn racines_frequentes(n: usize) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.racines_frequentes(n))?
+}
 
-    unsafe {
-        if let Some(ref mut arbre) = ARBRE {
-            // Réinitialiser l'arbre
-            *arbre = Tree::new();
+#[wasm_bindgen]
+pub fn derives_frequents(n: usize) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.derives_frequents(n))?
+}
 
-            // Restaurer toutes les racines et leurs dérivés
-            for (racine, derives) in data.racines {
-                arbre.insert(racine);
+// ============================================================================
+// SAUVEGARDE ET RESTAURATION (pour éviter la perte lors du rafraîchissement)
+// ============================================================================
 
-                for (mot, schema) in derives {
-                    arbre.ajouter_derive(racine, mot, schema);
-                }
-            }
+// Exporter toutes les données en JSON (pour localStorage)
+#[wasm_bindgen]
+pub fn exporter_donnees() -> Result<String, MorphoError> {
+    avec_moteur(|m| m.exporter_donnees())
+}
 
-            return true;
-        }
-    }
+// Importer les données depuis JSON (depuis localStorage)
+#[wasm_bindgen]
+pub fn importer_donnees(json: &str) -> Result<(), MorphoError> {
+    avec_moteur(|m| m.importer_donnees(json))?
+}
 
-    false
+// Importer en fusionnant avec les données actuelles (au lieu de les remplacer)
+// Retourne le rapport : racines/dérivés/schèmes ajoutés, doublons, conflits
+#[wasm_bindgen]
+pub fn fusionner_donnees(json: &str, politique: PolitiqueConflit) -> Result<JsValue, MorphoError> {
+    avec_moteur(|m| m.fusionner_donnees(json, politique))?
 }
@@ -9,32 +9,23 @@
 // 4. Remplacer afficher_arabe() par affichage HTML direct avec dir="rtl"
 // ============================================================================
 
-mod arbre;
-mod hashing;
-mod morpho_analyzer;
+mod cli;
 mod terminal_adapter; // ← À SUPPRIMER pour le web
 
-use arbre::Tree;
-use hashing::Scheme;
-use hashing::SchemeTable;
-use hashing::init_schemes;
-use morpho_analyzer::afficher_derives_stockes;
-use morpho_analyzer::afficher_famille;
-use morpho_analyzer::generer_et_stocker;
-use morpho_analyzer::generer_mot;
-use morpho_analyzer::valider_et_stocker;
+use moteur_morphologique::hashing::Scheme;
+use moteur_morphologique::lexique::Lexique;
+use moteur_morphologique::morpho_analyzer::afficher_analyse;
+use moteur_morphologique::morpho_analyzer::afficher_derives_stockes;
+use moteur_morphologique::morpho_analyzer::afficher_famille;
+use moteur_morphologique::morpho_analyzer::generer_et_stocker;
+use moteur_morphologique::morpho_analyzer::generer_mot;
+use moteur_morphologique::morpho_analyzer::valider_et_stocker;
 
 // ← À SUPPRIMER pour le web
 use terminal_adapter::{afficher_arabe, lire_ligne_simple, lire_racine_terminal, lire_texte_arabe};
 
 use std::io; // Pour io::stdout()
 
-// Valider qu'un schème contient au moins une lettre morphologique (ف, ع, ل)
-// Un schème valide doit pouvoir générer des dérivés en remplaçant ces lettres
-fn valider_scheme(nom: &str) -> bool {
-    nom.contains('ف') || nom.contains('ع') || nom.contains('ل')
-}
-
 // Afficher le menu principal
 fn afficher_menu() {
     println!();
@@ -56,7 +47,8 @@ fn afficher_menu() {
     println!("║ 13. Ajouter un schème                    ║");
     println!("║ 14. Modifier un schème                   ║");
     println!("║ 15. Supprimer un schème                  ║");
-    println!("║ 16. Quitter                              ║");
+    println!("║ 16. Analyser un mot (trouver la racine)  ║");
+    println!("║ 17. Quitter                              ║");
     println!("╚══════════════════════════════════════════╝");
     print!("Choix > ");
     // Forcer l'affichage immédiat du "Choix > "
@@ -65,13 +57,44 @@ fn afficher_menu() {
 }
 
 fn main() {
-    // Créer l'arbre (vide au départ)
-    let mut arbre = Tree::new();
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let lexique = match cli::extraire_lexique(&mut args) {
+        Ok(lexique) => lexique,
+        Err(message) => {
+            eprintln!("Erreur : {}", message);
+            std::process::exit(cli::USAGE);
+        }
+    };
 
-    // Créer la table de hachage avec les schèmes pré-chargés
-    let mut table_schemes: SchemeTable = init_schemes();
+    // Sans argument (ou avec "repl") : menu interactif
+    match args.first().map(|s| s.as_str()) {
+        None | Some("repl") => repl(lexique.as_deref()),
+        Some("aide") | Some("--help") | Some("-h") => println!("{}", cli::AIDE),
+        Some(_) => std::process::exit(cli::executer(&args, lexique.as_deref())),
+    }
+}
+
+// Choix du menu qui modifient l'arbre ou les schèmes (sauvegarde automatique)
+const CHOIX_MODIFIANTS: [&str; 10] = ["1", "2", "4", "6", "7", "8", "10", "13", "14", "15"];
+
+// Menu interactif (lecture des choix sur l'entrée standard)
+// Avec un lexique, l'état est relu au démarrage et réécrit après chaque modification
+fn repl(lexique: Option<&str>) {
+    // Créer l'arbre (vide au départ) et la table de hachage avec les schèmes pré-chargés,
+    // ou les relire depuis le lexique
+    let (mut arbre, mut table_schemes) = match cli::charger_lexique(lexique) {
+        Ok(etat) => etat,
+        Err(e) => {
+            // Ne pas démarrer : la première sauvegarde écraserait le fichier illisible
+            eprintln!("✗ Lexique illisible : {}", e);
+            std::process::exit(cli::ERREUR);
+        }
+    };
 
     println!("Bienvenue dans le Moteur Morphologique Arabe !");
+    if let Some(chemin) = lexique {
+        println!("Lexique : {} (sauvegarde automatique)", chemin);
+    }
 
     // Boucle principale du menu
     loop {
@@ -83,14 +106,17 @@ fn main() {
             "1" => {
                 println!("Entrez le chemin du fichier (ex: racines.txt) :");
                 let chemin = lire_ligne_simple();
-                arbre.charger_depuis_fichier(&chemin);
+                match arbre.charger_depuis_fichier(&chemin) {
+                    Ok(n) => println!("{} racines ajoutées depuis '{}'", n, chemin),
+                    Err(e) => println!("✗ Aucune racine chargée : {}", e),
+                }
             }
 
             // === 2. Ajouter une racine manuellement ===
             "2" => {
                 if let Some(racine) = lire_racine_terminal() {
                     arbre.insert(racine);
-                    let r: String = racine.iter().collect();
+                    let r = racine.to_string();
                     println!("Racine '{}' ajoutée.", afficher_arabe(&r));
                 }
             }
@@ -98,7 +124,7 @@ fn main() {
             // === 3. Chercher une racine dans l'arbre ===
             "3" => {
                 if let Some(racine) = lire_racine_terminal() {
-                    let r: String = racine.iter().collect();
+                    let r = racine.to_string();
                     if arbre.verify(racine) {
                         println!("✓ La racine '{}' existe dans l'arbre.", afficher_arabe(&r));
                     } else {
@@ -113,8 +139,8 @@ fn main() {
             // === 4. Supprimer une racine ===
             "4" => {
                 if let Some(racine) = lire_racine_terminal() {
-                    let r: String = racine.iter().collect();
-                    if arbre.delete(racine) {
+                    let r = racine.to_string();
+                    if arbre.delete(racine).is_ok() {
                         println!("✓ Racine '{}' supprimée avec succès.", afficher_arabe(&r));
                     } else {
                         println!(
@@ -129,7 +155,7 @@ fn main() {
             "5" => {
                 if let Some(racine) = lire_racine_terminal() {
                     if !arbre.verify(racine) {
-                        let r: String = racine.iter().collect();
+                        let r = racine.to_string();
                         println!(
                             "✗ La racine '{}' n'existe pas dans l'arbre. Ajoutez-la d'abord.",
                             afficher_arabe(&r)
@@ -145,7 +171,7 @@ fn main() {
                 if let Some(racine) = lire_racine_terminal() {
                     // Vérifier que la racine existe
                     if !arbre.verify(racine) {
-                        let r: String = racine.iter().collect();
+                        let r = racine.to_string();
                         println!(
                             "✗ La racine '{}' n'existe pas dans l'arbre. Ajoutez-la d'abord.",
                             afficher_arabe(&r)
@@ -154,25 +180,31 @@ fn main() {
                         println!("Entrez le nom du schème (ex: فاعل) :");
                         let nom_scheme = lire_texte_arabe();
 
-                        // Vérifier que le schème existe
-                        if !table_schemes.contains(&nom_scheme) {
-                            println!("✗ Schème '{}' non trouvé.", afficher_arabe(&nom_scheme));
-                        } else {
-                            // Générer le mot
-                            let mot = generer_mot(racine, &nom_scheme);
-
-                            // Stocker dans l'arbre
-                            let ok = arbre.ajouter_derive(racine, mot.clone(), nom_scheme.clone());
-
-                            if ok {
-                                println!(
-                                    "✓ Dérivé '{}' généré et stocké (schème: {})",
-                                    afficher_arabe(&mot),
-                                    afficher_arabe(&nom_scheme)
-                                );
-                            } else {
-                                println!("✗ Erreur lors du stockage du dérivé.");
+                        // Vérifier que le schème existe et qu'il a le bon nombre de radicaux
+                        // (identifiant, ou modèle qui ne désigne qu'un schème)
+                        match table_schemes.trouver(&nom_scheme) {
+                            Ok(scheme) if !scheme.compatible_avec(&racine) => println!(
+                                "✗ Le schème '{}' ne s'applique pas à une racine de {} lettres.",
+                                afficher_arabe(&nom_scheme),
+                                racine.longueur()
+                            ),
+                            Ok(scheme) => {
+                                // Générer le mot
+                                let mot = generer_mot(racine, scheme);
+
+                                // Stocker dans l'arbre, sous l'identifiant du schème
+                                match arbre.ajouter_derive(racine, mot.clone(), scheme.id.clone()) {
+                                    Ok(()) => println!(
+                                        "✓ Dérivé '{}' généré et stocké (schème: {})",
+                                        afficher_arabe(&mot),
+                                        afficher_arabe(&scheme.id)
+                                    ),
+                                    Err(e) => {
+                                        println!("✗ Erreur lors du stockage du dérivé : {}", e)
+                                    }
+                                }
                             }
+                            Err(e) => println!("✗ {}", e),
                         }
                     }
                 }
@@ -183,15 +215,25 @@ fn main() {
                 if let Some(racine) = lire_racine_terminal() {
                     // Vérifier que la racine existe
                     if !arbre.verify(racine) {
-                        let r: String = racine.iter().collect();
+                        let r = racine.to_string();
                         println!(
                             "✗ La racine '{}' n'existe pas dans l'arbre. Ajoutez-la d'abord.",
                             afficher_arabe(&r)
                         );
                     } else {
-                        generer_et_stocker(&mut arbre, racine, &table_schemes);
-                        // Afficher les dérivés stockés
-                        afficher_derives_stockes(&mut arbre, racine);
+                        match generer_et_stocker(&mut arbre, racine, &table_schemes) {
+                            Ok(n) => {
+                                let r = racine.to_string();
+                                println!(
+                                    "{} dérivés générés et stockés pour la racine '{}'",
+                                    n,
+                                    afficher_arabe(&r)
+                                );
+                                // Afficher les dérivés stockés
+                                afficher_derives_stockes(&mut arbre, racine);
+                            }
+                            Err(e) => println!("✗ {}", e),
+                        }
                     }
                 }
             }
@@ -204,28 +246,48 @@ fn main() {
                 if let Some(racine) = lire_racine_terminal() {
                     // Vérifier la racine dans l'arbre d'abord
                     if !arbre.verify(racine) {
-                        let r: String = racine.iter().collect();
+                        let r = racine.to_string();
                         println!(
                             "✗ La racine '{}' n'existe pas dans l'arbre. Ajoutez-la d'abord.",
                             afficher_arabe(&r)
                         );
                     } else {
-                        let (trouve, scheme) =
-                            valider_et_stocker(&mut arbre, &mot, racine, &table_schemes);
-                        let r: String = racine.iter().collect();
-                        if trouve {
-                            println!(
-                                "✓ OUI : '{}' appartient à la racine '{}'",
-                                afficher_arabe(&mot),
-                                afficher_arabe(&r)
-                            );
-                            println!("  Schème : {}", afficher_arabe(&scheme.unwrap()));
-                        } else {
-                            println!(
-                                "✗ NON : '{}' n'appartient pas à la racine '{}'",
-                                afficher_arabe(&mot),
-                                afficher_arabe(&r)
-                            );
+                        let r = racine.to_string();
+                        match valider_et_stocker(&mut arbre, &mot, racine, &table_schemes) {
+                            Err(e) => println!("✗ {}", e),
+                            Ok(validation) if validation.valide => {
+                                println!(
+                                    "✓ OUI : '{}' appartient à la racine '{}'",
+                                    afficher_arabe(&mot),
+                                    afficher_arabe(&r)
+                                );
+                                if validation.ambigu {
+                                    println!("  Mot ambigu : toutes les lectures sont stockées");
+                                }
+                                for lecture in &validation.lectures {
+                                    let flexion = lecture
+                                        .flexion
+                                        .map(|f| f.etiquette())
+                                        .or_else(|| {
+                                            lecture.conjugaison.as_ref().map(|c| c.etiquette())
+                                        })
+                                        .map(|e| format!(" ({})", e))
+                                        .unwrap_or_default();
+                                    println!(
+                                        "  Schème : {} → {}{}",
+                                        afficher_arabe(&lecture.schema),
+                                        afficher_arabe(&lecture.forme),
+                                        flexion
+                                    );
+                                }
+                            }
+                            Ok(_) => {
+                                println!(
+                                    "✗ NON : '{}' n'appartient pas à la racine '{}'",
+                                    afficher_arabe(&mot),
+                                    afficher_arabe(&r)
+                                );
+                            }
                         }
                     }
                 }
@@ -243,7 +305,7 @@ fn main() {
                 if let Some(racine) = lire_racine_terminal() {
                     // Vérifier que la racine existe
                     if !arbre.verify(racine) {
-                        let r: String = racine.iter().collect();
+                        let r = racine.to_string();
                         println!(
                             "✗ La racine '{}' n'existe pas dans l'arbre.",
                             afficher_arabe(&r)
@@ -291,26 +353,21 @@ fn main() {
                     println!("Erreur: le nom ne peut pas être vide.");
                 } else if table_schemes.contains(&nom) {
                     println!(
-                        "Le schème '{}' existe déjà. Utilisez l'option 10 pour le modifier.",
+                        "Le schème '{}' existe déjà. Utilisez l'option 14 pour le modifier.",
                         afficher_arabe(&nom)
                     );
-                } else if !valider_scheme(&nom) {
-                    println!("✗ Erreur: le schème doit contenir au moins un ف, ع, ou ل");
-                    println!("   (nécessaire pour générer des dérivés morphologiques)");
                 } else {
-                    println!("Entrez le pattern (ex: ف-ا-ع-ل) :");
+                    println!("Entrez le pattern (ex: ف-ا-ع-ل, ou Entrée pour le déduire du nom) :");
                     let pattern = lire_texte_arabe();
                     println!("Entrez la description :");
                     let description = lire_ligne_simple();
-                    table_schemes.insert(
-                        nom.clone(),
-                        Scheme {
-                            nom: nom.clone(),
-                            pattern,
-                            description,
+                    match Scheme::new(&nom, &pattern, &description) {
+                        Ok(scheme) => match table_schemes.try_insert(nom.clone(), scheme) {
+                            Ok(()) => println!("✓ Schème '{}' ajouté.", afficher_arabe(&nom)),
+                            Err(e) => println!("✗ {}", e),
                         },
-                    );
-                    println!("✓ Schème '{}' ajouté.", afficher_arabe(&nom));
+                        Err(e) => println!("✗ Pattern invalide ({})", e),
+                    }
                 }
             }
 
@@ -335,28 +392,37 @@ fn main() {
                             .collect()
                     };
 
-                    if !valider_scheme(&nom_final) {
-                        println!("✗ Erreur: le schème doit contenir au moins un ف, ع, ou ل");
-                    } else {
-                        println!("Nouveau pattern (ex: ف-ا-ع-ل) :");
-                        let pattern = lire_texte_arabe();
-                        println!("Nouvelle description :");
-                        let description = lire_ligne_simple();
-
-                        // Si le nom change, supprimer l'ancien
-                        if nom_final != nom {
-                            table_schemes.delete(&nom);
-                        }
+                    println!("Nouveau pattern (ex: ف-ا-ع-ل, ou Entrée pour le déduire du nom) :");
+                    let pattern = lire_texte_arabe();
+                    println!("Nouvelle description :");
+                    let description = lire_ligne_simple();
 
-                        table_schemes.insert(
-                            nom_final.clone(),
-                            Scheme {
-                                nom: nom_final.clone(),
-                                pattern,
-                                description,
-                            },
-                        );
-                        println!("✓ Schème '{}' modifié.", afficher_arabe(&nom_final));
+                    match Scheme::new(&nom_final, &pattern, &description) {
+                        Ok(mut scheme) => {
+                            // Catégorie, pluriels et propriétés ne se saisissent pas
+                            // ici : ceux de l'ancien schème sont conservés
+                            if let Some(ancien) = table_schemes.get(&nom) {
+                                scheme.categorie = ancien.categorie;
+                                scheme.pluriels = ancien.pluriels.clone();
+                                scheme.proprietes = ancien.proprietes;
+                            }
+                            // Si le nom change, le nouveau ne doit pas être pris ;
+                            // l'ancien n'est supprimé qu'une fois le nouveau inséré
+                            let resultat = if nom_final != nom {
+                                table_schemes
+                                    .try_insert(nom_final.clone(), scheme)
+                                    .and_then(|()| table_schemes.delete(&nom))
+                            } else {
+                                table_schemes.remplacer(&nom, scheme)
+                            };
+                            match resultat {
+                                Ok(()) => {
+                                    println!("✓ Schème '{}' modifié.", afficher_arabe(&nom_final))
+                                }
+                                Err(e) => println!("✗ {}", e),
+                            }
+                        }
+                        Err(e) => println!("✗ Pattern invalide ({})", e),
                     }
                 }
             }
@@ -365,23 +431,37 @@ fn main() {
             "15" => {
                 println!("Entrez le nom du schème à supprimer (ex: فاعل) :");
                 let nom = lire_texte_arabe();
-                if table_schemes.delete(&nom) {
+                if table_schemes.delete(&nom).is_ok() {
                     println!("✓ Schème '{}' supprimé.", afficher_arabe(&nom));
                 } else {
                     println!("✗ Schème '{}' non trouvé.", afficher_arabe(&nom));
                 }
             }
 
-            // === 16. Quitter ===
+            // === 16. Analyser un mot sans connaître sa racine ===
             "16" => {
+                println!("Entrez le mot à analyser (ex: مستخرج) :");
+                let mot = lire_texte_arabe();
+                afficher_analyse(&mot, &table_schemes, &arbre);
+            }
+
+            // === 17. Quitter ===
+            "17" => {
                 println!("Au revoir !");
                 break;
             }
 
             // Choix invalide
             _ => {
-                println!("Choix invalide. Tapez un nombre entre 1 et 16.");
+                println!("Choix invalide. Tapez un nombre entre 1 et 17.");
             }
         }
+
+        if CHOIX_MODIFIANTS.contains(&choix.as_str())
+            && let Some(chemin) = lexique
+            && let Err(e) = Lexique::depuis(&arbre, &table_schemes).ecrire_fichier(chemin)
+        {
+            println!("✗ Sauvegarde du lexique impossible : {}", e);
+        }
     }
 }
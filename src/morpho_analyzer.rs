@@ -2,58 +2,122 @@
 // Ce module contient le cœur du projet : générer des mots à partir d'une racine et d'un schème
 
 // Fonction principale : générer un mot dérivé
-// Elle prend une racine [char; 3] et un nom de schème (String)
-// Elle remplace ف par la 1ère lettre, ع par la 2ème, ل par la 3ème
+// Elle remplit le gabarit du schème (son pattern analysé) avec les radicaux :
+// chaque case radical n°1..4 reçoit la consonne correspondante de la racine,
+// les lettres fixes et les diacritiques sont recopiés tels quels
+// Le mot produit est entièrement vocalisé ; `sans_diacritiques` (gabarit.rs)
+// en donne la forme nue
 //
-// Exemple : racine = ['ك', 'ت', 'ب'], schème = "فاعل"
-//   ف → ك, ع → ت, ل → ب  ==>  résultat = "كاتب"
-
-pub fn generer_mot(racine: [char; 3], scheme_nom: &str) -> String {
-    let mut resultat = String::new();
-
-    // Parcourir chaque caractère du schème
-    for c in scheme_nom.chars() {
-        if c == 'ف' {
-            // Remplacer ف par la 1ère consonne de la racine
-            resultat.push(racine[0]);
-        } else if c == 'ع' {
-            // Remplacer ع par la 2ème consonne de la racine
-            resultat.push(racine[1]);
-        } else if c == 'ل' {
-            // Remplacer ل par la 3ème consonne de la racine
-            resultat.push(racine[2]);
-        } else {
-            // Garder le caractère tel quel (ا, م, ت, و, etc.)
-            resultat.push(c);
-        }
+// Exemple : racine = ك ت ب, schème فاعل (pattern "فَ-ا-عِ-ل")
+//   [1]َ ا [2]ِ [3]  ==>  résultat = "كَاتِب"
+// Exemple : racine = ت ر ج م, schème مفعلل (pattern "مُ-فَ-عْ-لِ-ل")
+//   مُ [1]َ [2]ْ [3]ِ [4]  ==>  résultat = "مُتَرْجِم"
+//
+// Pour les racines faibles, le gabarit est d'abord remplacé par sa variante
+// (voir phonologie.rs) : ق و ل + فاعل ==> modèle "فَائِل" ==> "قَائِل"
+// Les variantes sont cherchées par identifiant : فاعل_III (قَاوَلَ) n'a pas
+// celles du participe فاعل (قَائِل), bien que les deux aient le même nom
+
+pub fn generer_mot(racine: Racine, scheme: &Scheme) -> String {
+    match variante_pour(&racine, &scheme.id) {
+        Some(variante) => generer_gabarit(racine, &Gabarit::depuis_nom(variante), &scheme.id),
+        None => generer_gabarit(racine, &scheme.gabarit, &scheme.id),
+    }
+}
+
+// Remplir un gabarit avec la racine et corriger l'orthographe (hamzas,
+// assimilation du ت de la forme VIII), sans chercher de variante
+// `scheme_id` pilote les règles propres à certains schèmes (افتعل، مفعّل...)
+pub fn generer_gabarit(racine: Racine, gabarit: &Gabarit, scheme_id: &str) -> String {
+    ajuster_orthographe(&gabarit.remplir(racine.lettres()), scheme_id)
+}
+
+// Générer un mot à partir de l'identifiant d'un schème de la table, ou d'un
+// nom qui ne désigne qu'un schème (voir SchemeTable::trouver)
+// Erreur si le schème est inconnu, ambigu ou n'a pas le nombre de radicaux de la racine
+pub fn generer_par_nom(
+    racine: Racine,
+    nom_scheme: &str,
+    table: &SchemeTable,
+) -> Result<String, MorphoError> {
+    let scheme = table.trouver(nom_scheme)?;
+    if !scheme.compatible_avec(&racine) {
+        return Err(MorphoError::SchemeIncompatible {
+            scheme: scheme.id.clone(),
+            racine,
+        });
     }
+    Ok(generer_mot(racine, scheme))
+}
 
-    resultat
+// Un membre de la famille d'une racine : le mot, son schème et sa catégorie
+// Pour un singulier relié à des pluriels brisés, `pluriels` donne les paires
+// singulier/pluriel : مَكْتَب → [مَكَاتِب (مفاعل)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MembreFamille {
+    pub mot: String,
+    pub schema: String,
+    pub categorie: Categorie,
+    pub pluriels: Vec<Derive>,
 }
 
 // Générer TOUS les dérivés d'une racine à partir de la table de hachage des schèmes
-// Retourne un vecteur de (nom_du_schème, mot_généré)
-pub fn generer_famille(racine: [char; 3], table: &SchemeTable) -> Vec<(String, String)> {
-    let mut resultats: Vec<(String, String)> = Vec::new();
+// Les membres sont regroupés par catégorie (verbes, participes, masdars, noms...)
+pub fn generer_famille(racine: Racine, table: &SchemeTable) -> Vec<MembreFamille> {
+    let mut resultats: Vec<MembreFamille> = Vec::new();
 
     // Parcourir tous les schèmes de la table de hachage
+    // (seulement ceux qui ont le même nombre de radicaux que la racine)
     for scheme in table.get_all_schemes() {
-        let mot = generer_mot(racine, &scheme.nom);
-        resultats.push((scheme.nom.clone(), mot));
+        if !scheme.compatible_avec(&racine) {
+            continue;
+        }
+        let pluriels = table
+            .pluriels_de(scheme)
+            .into_iter()
+            .filter(|pluriel| pluriel.compatible_avec(&racine))
+            .map(|pluriel| Derive::new(generer_mot(racine, pluriel), pluriel.id.clone()))
+            .collect();
+        resultats.push(MembreFamille {
+            mot: generer_mot(racine, scheme),
+            schema: scheme.id.clone(),
+            categorie: scheme.categorie,
+            pluriels,
+        });
     }
 
+    // L'ordre de la table de hachage n'a pas de sens : tri par catégorie, puis schème
+    resultats.sort_by(|a, b| (a.categorie, &a.schema).cmp(&(b.categorie, &b.schema)));
     resultats
 }
 
 // Afficher toute la famille morphologique d'une racine
-pub fn afficher_famille(racine: [char; 3], table: &SchemeTable) {
-    let r: String = racine.iter().collect();
-    println!("=== Famille morphologique de {} ===", r);
+pub fn afficher_famille(racine: Racine, table: &SchemeTable) {
+    println!(
+        "=== Famille morphologique de {} ({}) ===",
+        racine,
+        classifier(&racine).nom_arabe()
+    );
 
     let famille = generer_famille(racine, table);
 
-    for (scheme, mot) in &famille {
-        println!("  {} → {}", scheme, mot);
+    let mut categorie = None;
+    for membre in &famille {
+        if categorie != Some(membre.categorie) {
+            categorie = Some(membre.categorie);
+            println!("-- {} --", membre.categorie.nom_arabe());
+        }
+        let pluriels: Vec<&str> = membre.pluriels.iter().map(|p| p.mot.as_str()).collect();
+        if pluriels.is_empty() {
+            println!("  {} → {}", membre.schema, membre.mot);
+        } else {
+            println!(
+                "  {} → {}  (ج. {})",
+                membre.schema,
+                membre.mot,
+                pluriels.join("، ")
+            );
+        }
     }
 
     println!("Total: {} dérivés", famille.len());
@@ -65,40 +129,227 @@ pub fn afficher_famille(racine: [char; 3], table: &SchemeTable) {
 
 // Vérifier si un mot appartient à une racine donnée
 // Principe : on essaie chaque schème, on génère le mot, et on compare
+// La comparaison se fait au niveau de détail de la saisie, après normalisation
+// (voir `equivalent` dans normalisation.rs : اكل ≈ أَكَلَ, مكاتبه ≈ مُكَاتَبَة) :
+// un mot nu accepte n'importe quelle vocalisation, un mot vocalisé doit porter
+// les voyelles de la forme générée
 //
 // Exemple : valider("مكتوب", ['ك','ت','ب'], schemes)
-//   On essaie فاعل → كاتب ≠ مكتوب
-//   On essaie مفعول → مكتوب == مكتوب  ✅ trouvé !
-//   Retourne (true, Some("مفعول"))
+//   On essaie فاعل → كَاتِب ≠ مكتوب
+//   On essaie مفعول → مَكْتُوب ≈ مكتوب  ✅ trouvé !
+//   Retourne (true, ["مفعول"])
+// Exemple : valider("كُتِبَ", ...) ne correspond pas à كَاتِب (فاعل) ; seul un
+//   schème qui donnerait exactement ces voyelles serait accepté
+
+// Retourne (trouvé, identifiants de tous les schèmes reconnus, le meilleur en tête)
+pub fn valider_mot(mot: &str, racine: Racine, table: &SchemeTable) -> (bool, Vec<String>) {
+    let schemes: Vec<String> = valider_mot_vocalise(mot, racine, table)
+        .into_iter()
+        .map(|(schema, _)| schema)
+        .collect();
+    (!schemes.is_empty(), schemes)
+}
+
+// Comme valider_mot, mais retourne aussi la forme vocalisée reconnue
+// Retourne la liste (identifiant du schème, forme générée), dans l'ordre de
+// valider_mot_complet, vide si aucun schème ne correspond
+pub fn valider_mot_vocalise(
+    mot: &str,
+    racine: Racine,
+    table: &SchemeTable,
+) -> Vec<(String, String)> {
+    valider_mot_complet(mot, racine, table)
+        .lectures
+        .into_iter()
+        .map(|l| (l.schema, l.forme))
+        .collect()
+}
+
+// Une lecture du mot : un schème qui le reproduit, avec ses métadonnées
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Lecture {
+    // Identifiant du schème (فاعل_III), puis son nom (فاعل)
+    pub schema: String,
+    pub nom: String,
+    // Forme vocalisée générée (la saisie peut être nue)
+    pub forme: String,
+    pub categorie: Categorie,
+    pub proprietes: Proprietes,
+    pub description: String,
+    // Diacritiques que la forme ajoute à la saisie (0 : saisie complète)
+    pub ajouts: usize,
+    // Désinence reconnue (كاتبون : masculin pluriel nominatif), absente si le
+    // mot est la forme de base du schème (voir flexion.rs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flexion: Option<Flexion>,
+    // Verbe conjugué (يَكْتُبُونَ : inaccompli 3mp) du schème de l'accompli ;
+    // `proprietes` sont alors celles du mot conjugué (voir conjugaison.rs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conjugaison: Option<Conjugaison>,
+}
+
+// Résultat complet d'une validation
+// `ambigu` : plusieurs schèmes reproduisent la saisie (كاتب : فاعل et فاعل_III)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Validation {
+    pub mot: String,
+    pub valide: bool,
+    pub ambigu: bool,
+    pub lectures: Vec<Lecture>,
+}
+
+// Toutes les lectures d'un mot pour une racine
+// Une saisie peu vocalisée peut correspondre à plusieurs schèmes (كاتب : فاعل
+// et فاعل_III ; كتب : فَعَلَ et فَعَّلَ) : toutes sont retournées, celle qui
+// ajoute le moins de diacritiques à la saisie en premier, puis par identifiant.
+// L'ordre ne dépend donc pas des cases de la table de hachage (redimensionnement,
+// schèmes ajoutés)
+// Les formes fléchies de chaque schème sont aussi essayées (كاتبون : فاعل au
+// pluriel masculin) ; à forme égale, la forme de base d'un autre schème est
+// préférée (كَاتِبَة est فاعلة, pas فاعل au féminin), puis le schème du même
+// genre que la désinence (كَاتِبَات : فاعلة, pas فاعل)
+// Un schème verbal dont la forme de base ne correspond pas est cherché dans
+// le tableau de conjugaison de sa forme (يكتبون : فعل, inaccompli 3mp)
+pub fn valider_mot_complet(mot: &str, racine: Racine, table: &SchemeTable) -> Validation {
+    lectures_du_mot(mot, racine, table, true)
+}
+
+// Lectures d'un mot, avec ou sans les verbes conjugués (l'analyse d'un mot
+// fléchi n'a besoin que des formes fléchies)
+fn lectures_du_mot(
+    mot: &str,
+    racine: Racine,
+    table: &SchemeTable,
+    conjugaisons: bool,
+) -> Validation {
+    let mut lectures: Vec<Lecture> = Vec::new();
 
-pub fn valider_mot(mot: &str, racine: [char; 3], table: &SchemeTable) -> (bool, Option<String>) {
     // Parcourir chaque schème de la table de hachage
     for scheme in table.get_all_schemes() {
-        // Générer le mot avec ce schème (on réutilise generer_mot)
-        let mot_genere = generer_mot(racine, &scheme.nom);
+        if !scheme.compatible_avec(&racine) {
+            continue;
+        }
+
+        // Générer le mot avec ce schème (on réutilise generer_mot), puis ses
+        // formes fléchies
+        let mot_genere = generer_mot(racine, scheme);
+        let mut formes = vec![(mot_genere.clone(), None)];
+        formes.extend(
+            flechir(&mot_genere, &scheme.proprietes)
+                .into_iter()
+                .map(|f| (f.mot, Some(f.flexion))),
+        );
+
+        for (forme, flexion) in formes {
+            // Comparer la forme générée avec le mot à valider
+            if !equivalent(mot, &forme) {
+                continue;
+            }
+            // Le mot correspond à ce schème : combien de diacritiques en plus ?
+            let ajouts = nombre_diacritiques(&forme).saturating_sub(nombre_diacritiques(mot));
+            lectures.push(Lecture {
+                schema: scheme.id.clone(),
+                nom: scheme.nom.clone(),
+                forme,
+                categorie: scheme.categorie,
+                proprietes: scheme.proprietes,
+                description: scheme.description.clone(),
+                ajouts,
+                flexion,
+                conjugaison: None,
+            });
+        }
 
-        // Comparer le mot généré avec le mot à valider
-        if mot_genere == mot {
-            // Trouvé ! Le mot correspond à ce schème
-            return (true, Some(scheme.nom.clone()));
+        // Verbe conjugué, si le mot n'est pas la forme de base du schème
+        let base_reconnue = lectures
+            .iter()
+            .any(|l| l.schema == scheme.id && l.flexion.is_none());
+        if !conjugaisons || base_reconnue {
+            continue;
+        }
+        let Some(forme_verbale) = forme_du_scheme(scheme) else {
+            continue;
+        };
+        for (forme, conjugaison) in reconnaitre(mot, racine, forme_verbale) {
+            let ajouts = nombre_diacritiques(&forme).saturating_sub(nombre_diacritiques(mot));
+            lectures.push(Lecture {
+                schema: scheme.id.clone(),
+                nom: scheme.nom.clone(),
+                forme,
+                categorie: scheme.categorie,
+                proprietes: conjugaison.proprietes(scheme.proprietes),
+                description: scheme.description.clone(),
+                ajouts,
+                flexion: None,
+                conjugaison: Some(conjugaison),
+            });
         }
     }
 
-    // Aucun schème ne correspond
-    (false, None)
+    // Une forme fléchie ou conjuguée identique à la forme de base d'un schème
+    // est écartée
+    let est_base = |l: &Lecture| l.flexion.is_none() && l.conjugaison.is_none();
+    let bases: Vec<String> = lectures
+        .iter()
+        .filter(|l| est_base(l))
+        .map(|l| l.forme.clone())
+        .collect();
+    lectures.retain(|l| est_base(l) || !bases.contains(&l.forme));
+
+    // L'ordre de la table de hachage n'a pas de sens : tri déterministe
+    // (formes de base avant formes fléchies et conjuguées à nombre d'ajouts égal)
+    let cle = |l: &Lecture| {
+        (
+            l.ajouts,
+            !est_base(l),
+            l.flexion
+                .is_some_and(|f| l.proprietes.genre != Some(f.genre)),
+            l.schema.clone(),
+            l.flexion.map(|f| f.etiquette()),
+            l.conjugaison.as_ref().map(|c| c.etiquette()),
+        )
+    };
+    lectures.sort_by_key(cle);
+
+    // Une même forme fléchie n'est gardée qu'une fois, pour le premier schème
+    let mut vues: Vec<(String, Flexion)> = Vec::new();
+    lectures.retain(|l| match l.flexion {
+        None => true,
+        Some(flexion) => {
+            let vue = (l.forme.clone(), flexion);
+            let nouvelle = !vues.contains(&vue);
+            vues.push(vue);
+            nouvelle
+        }
+    });
+    Validation {
+        mot: mot.to_string(),
+        valide: !lectures.is_empty(),
+        ambigu: lectures.len() > 1,
+        lectures,
+    }
+}
+
+// Voyelles et shaddas d'un mot
+fn nombre_diacritiques(mot: &str) -> usize {
+    mot.chars()
+        .filter(|c| *c == SHADDA || est_diacritique(*c))
+        .count()
 }
 
 // Version avec affichage : vérifie et affiche le résultat
 #[allow(dead_code)]
-pub fn afficher_validation(mot: &str, racine: [char; 3], table: &SchemeTable) {
-    let r: String = racine.iter().collect();
-    let (trouve, scheme) = valider_mot(mot, racine, table);
+pub fn afficher_validation(mot: &str, racine: Racine, table: &SchemeTable) {
+    let (trouve, schemes) = valider_mot(mot, racine, table);
 
     if trouve {
-        println!("✓ OUI : '{}' appartient à la racine '{}'", mot, r);
-        println!("  Schème utilisé : {}", scheme.unwrap());
+        println!("✓ OUI : '{}' appartient à la racine '{}'", mot, racine);
+        println!("  Schème(s) reconnu(s) : {}", schemes.join("، "));
     } else {
-        println!("✗ NON : '{}' n'appartient pas à la racine '{}'", mot, r);
+        println!(
+            "✗ NON : '{}' n'appartient pas à la racine '{}'",
+            mot, racine
+        );
     }
 }
 
@@ -106,60 +357,512 @@ pub fn afficher_validation(mot: &str, racine: [char; 3], table: &SchemeTable) {
 // GESTION DES DÉRIVÉS VALIDÉS (lien arbre ↔ dérivation)
 // ========================================================
 
-use crate::arbre::Tree;
+use serde::{Deserialize, Serialize};
+
+use crate::arbre::{Derive, Tree};
+use crate::conjugaison::{Conjugaison, forme_du_scheme, modeles_conjugues, reconnaitre};
+use crate::erreur::MorphoError;
+use crate::flexion::{Flexion, bases_possibles, flechir};
+use crate::gabarit::{Gabarit, SHADDA, Segment, est_diacritique, sans_diacritiques};
 use crate::hashing::SchemeTable;
+use crate::hashing::{Categorie, Scheme};
+use crate::normalisation::{Normalisation, equivalent};
+use crate::phonologie::{
+    FORMES_TA_FORME_VIII, ajuster_orthographe, classifier, est_faible, est_hamza, variante_pour,
+    variantes_possibles,
+};
+use crate::proprietes::Proprietes;
+use crate::racine::Racine;
+use crate::segmentation::{Segmentation, segmenter};
 
 // Générer tous les dérivés d'une racine ET les stocker dans l'arbre
-// Retourne le nombre de dérivés ajoutés
-pub fn generer_et_stocker(arbre: &mut Tree, racine: [char; 3], table: &SchemeTable) -> u32 {
+// Retourne le nombre de dérivés stockés (RacineIntrouvable si la racine n'est pas dans l'arbre)
+pub fn generer_et_stocker(
+    arbre: &mut Tree,
+    racine: Racine,
+    table: &SchemeTable,
+) -> Result<u32, MorphoError> {
+    // Refuser d'emblée une racine absente plutôt que d'échouer sur chaque schème
+    let noeud = arbre.noeud(racine)?;
     let mut compteur: u32 = 0;
 
     // Pour chaque schème de la table de hachage, on génère le mot et on le stocke
     for scheme in table.get_all_schemes() {
-        let mot = generer_mot(racine, &scheme.nom);
+        if !scheme.compatible_avec(&racine) {
+            continue;
+        }
+        let mot = generer_mot(racine, scheme);
 
         // Stocker dans le nœud de la racine dans l'arbre
-        let ok = arbre.ajouter_derive(racine, mot.clone(), scheme.nom.clone());
-        if ok {
-            compteur = compteur + 1;
-        }
+        noeud.ajouter_derive(mot, scheme.id.clone());
+        compteur += 1;
     }
 
-    let r: String = racine.iter().collect();
-    println!(
-        "{} dérivés générés et stockés pour la racine '{}'",
-        compteur, r
-    );
-    compteur
+    Ok(compteur)
 }
 
 // Valider un mot ET le stocker si valide
-// Retourne (trouvé, schème trouvé)
+// Retourne la validation complète ; RacineIntrouvable si le mot est valide
+// mais que la racine n'est pas dans l'arbre
+// Ce sont les formes vocalisées générées qui sont stockées, même si la saisie
+// était nue : une par lecture (كاتب ambigu → كَاتِب (فاعل) et كَاتَبَ (فاعل_III)),
+// avec ses traits fléchis (كاتبون → كَاتِبُون, masculin pluriel nominatif)
+// Un verbe conjugué n'est pas un dérivé : c'est la forme de base de son schème
+// qui est stockée (يكتبون → كَتَبَ)
 pub fn valider_et_stocker(
     arbre: &mut Tree,
     mot: &str,
-    racine: [char; 3],
+    racine: Racine,
     table: &SchemeTable,
-) -> (bool, Option<String>) {
-    let (trouve, scheme) = valider_mot(mot, racine, table);
-
-    if trouve {
-        // Le mot est valide → on le stocke dans l'arbre
-        let schema = scheme.clone().unwrap();
-        arbre.ajouter_derive(racine, mot.to_string(), schema);
+) -> Result<Validation, MorphoError> {
+    let validation = valider_mot_complet(mot, racine, table);
+    if validation.valide {
+        // Le mot est valide → on vérifie la racine avant de stocker quoi que ce soit
+        let noeud = arbre.noeud(racine)?;
+        for lecture in &validation.lectures {
+            let mot = match (&lecture.conjugaison, table.get(&lecture.schema)) {
+                (Some(_), Some(scheme)) => generer_mot(racine, scheme),
+                _ => lecture.forme.clone(),
+            };
+            noeud.ajouter(Derive {
+                mot,
+                schema: lecture.schema.clone(),
+                flexion: lecture.flexion,
+                occurrences: 0,
+            });
+        }
     }
-
-    (trouve, scheme)
+    Ok(validation)
 }
 
 // Afficher les dérivés stockés pour une racine dans l'arbre
-pub fn afficher_derives_stockes(arbre: &mut Tree, racine: [char; 3]) {
+pub fn afficher_derives_stockes(arbre: &mut Tree, racine: Racine) {
     let noeud = arbre.chercher_noeud(racine);
     match noeud {
         Some(n) => n.afficher_derives(),
+        None => println!("Racine '{}' non trouvée dans l'arbre.", racine),
+    }
+}
+
+// ========================================================
+// ANALYSE INVERSE : retrouver racine et schème à partir du mot seul
+// ========================================================
+// Principe : on superpose le mot au gabarit de chaque schème (et à ses variantes
+// pour les racines faibles). Les cases radicaux sont des jokers qui capturent
+// les lettres, les autres cases doivent correspondre exactement.
+// Chaque racine candidate est ensuite VÉRIFIÉE en régénérant le mot avec
+// generer_mot : seules les hypothèses exactes sont retournées.
+//
+// Exemple : analyser_mot("مستخرج")
+//   مستفعل : م-س-ت-[ف]-[ع]-[ل]  ==>  خ ر ج  ✅
+
+// Une hypothèse d'analyse : (racine, schème), et si la racine est dans l'arbre
+// `flexion` : désinence retirée pour retrouver la forme du schème (كاتبون)
+// `conjugaison` : place du mot dans le tableau de conjugaison du schème verbal
+// (يكتبون : فعل, inaccompli 3mp)
+#[derive(Clone, Debug)]
+pub struct Hypothese {
+    pub racine: Racine,
+    pub scheme: String,
+    pub dans_arbre: bool,
+    pub flexion: Option<Flexion>,
+    pub conjugaison: Option<Conjugaison>,
+}
+
+impl Hypothese {
+    // Propriétés du mot analysé : celles du schème, ou du mot conjugué
+    pub fn proprietes(&self, table: &SchemeTable) -> Proprietes {
+        let proprietes = table
+            .get(&self.scheme)
+            .map(|s| s.proprietes)
+            .unwrap_or_default();
+        match &self.conjugaison {
+            Some(conjugaison) => conjugaison.proprietes(proprietes),
+            None => proprietes,
+        }
+    }
+}
+
+// Superposer un mot nu à un gabarit : retourne les radicaux capturés
+// (None pour un radical absent du gabarit, ex: le ع de قال = "فال")
+// Les voyelles du gabarit sont ignorées ; sa shadda peut manquer dans le mot
+fn extraire_radicaux(
+    mot: &[char],
+    gabarit: &Gabarit,
+    scheme_id: &str,
+) -> Option<[Option<char>; 4]> {
+    let forme_viii = scheme_id == "افتعل" || scheme_id == "مفتعل";
+    let mut radicaux: [Option<char>; 4] = [None; 4];
+    let mut lettres = mot.iter().peekable();
+
+    for segment in &gabarit.segments {
+        if matches!(segment, Segment::Diacritique(_)) {
+            continue;
+        }
+        // Une shadda omise dans la saisie (مد pour مدّ) est acceptée ici ;
+        // la vérification finale tient compte du niveau de détail de la saisie
+        if *segment == Segment::Gemination && lettres.peek() != Some(&&SHADDA) {
+            continue;
+        }
+        let c = lettres.next()?;
+        match segment {
+            Segment::Radical(n) => {
+                // Un même radical répété doit capturer la même lettre
+                match radicaux[n - 1] {
+                    Some(deja) if deja != *c => return None,
+                    _ => radicaux[n - 1] = Some(*c),
+                }
+            }
+            // Le ت infixé de la forme VIII a pu s'assimiler (اصطبر، ازدحم، ادّعى)
+            Segment::Lettre('ت') if forme_viii && FORMES_TA_FORME_VIII.contains(c) => {}
+            Segment::Lettre(l) => {
+                if l != c {
+                    return None;
+                }
+            }
+            Segment::Diacritique(_) | Segment::Gemination => {}
+        }
+    }
+
+    if lettres.next().is_some() {
+        return None;
+    }
+    Some(radicaux)
+}
+
+// Lettres possibles pour un radical capturé : une hamza (quel que soit son
+// support) ou une lettre faible peut provenir de n'importe quelle autre
+// (سائل ← س أ ل, مسؤول ← س أ ل, إيمان ← أ م ن)
+// Un radical absent du mot peut aussi être la copie du précédent (مضعف : امتدّ ← م د د)
+// Les lettres qui ne sont jamais des radicaux (ا، ى، ة، ّ) ne donnent rien
+fn alternatives(capture: Option<char>, precedent: Option<char>) -> Vec<char> {
+    let variables = vec!['أ', 'ء', 'و', 'ي'];
+    match capture {
         None => {
-            let r: String = racine.iter().collect();
-            println!("Racine '{}' non trouvée dans l'arbre.", r);
+            let mut v = variables;
+            if let Some(p) = precedent
+                && !v.contains(&p)
+            {
+                v.push(p);
+            }
+            v
+        }
+        Some(c) if est_hamza(c) || est_faible(c) => variables,
+        Some('ا') | Some('ة') | Some('ّ') => Vec::new(),
+        Some(c) => vec![c],
+    }
+}
+
+// Analyser un mot : toutes les hypothèses (racine, schème) qui le génèrent
+// Les racines présentes dans l'arbre sont classées en premier
+// Le mot peut être nu ou vocalisé : la superposition se fait sur sa forme nue,
+// la vérification au niveau de détail de la saisie (كَتَبَ écarte كَاتِب)
+// Un mot fléchi (كاتبون، مكتوبين) est analysé par sa base (voir flexion.rs) :
+// l'hypothèse n'est gardée que si la racine reproduit le mot entier avec une
+// désinence admise par le schème
+// Un verbe conjugué (يكتبون) est superposé aux mots conjugués de la forme de
+// chaque schème verbal, puis vérifié dans le tableau de conjugaison
+pub fn analyser_mot(mot: &str, table: &SchemeTable, arbre: &Tree) -> Vec<Hypothese> {
+    let mut hypotheses = superposer(mot, table, arbre);
+
+    for base in bases_possibles(mot) {
+        for h in superposer(&base, table, arbre) {
+            let lectures = lectures_du_mot(mot, h.racine, table, false).lectures;
+            for lecture in lectures {
+                if lecture.schema != h.scheme || lecture.flexion.is_none() {
+                    continue;
+                }
+                let deja = hypotheses.iter().any(|x| {
+                    x.scheme == h.scheme
+                        && x.racine.cle() == h.racine.cle()
+                        && x.flexion == lecture.flexion
+                });
+                if !deja {
+                    hypotheses.push(Hypothese {
+                        flexion: lecture.flexion,
+                        ..h.clone()
+                    });
+                }
+            }
+        }
+    }
+
+    // Pas de conjugaison pour un schème dont le mot est déjà la forme de base
+    for h in superposer_conjugaisons(mot, table, arbre) {
+        let deja = hypotheses.iter().any(|x| {
+            x.scheme == h.scheme
+                && x.racine.cle() == h.racine.cle()
+                && x.flexion.is_none()
+                && (x.conjugaison.is_none() || x.conjugaison == h.conjugaison)
+        });
+        if !deja {
+            hypotheses.push(h);
+        }
+    }
+
+    // Classement : racines connues d'abord, puis ordre alphabétique (déterministe),
+    // la forme de base du schème avant ses formes fléchies et conjuguées
+    hypotheses.sort_by_key(|h| {
+        (
+            !h.dans_arbre,
+            h.racine.to_string(),
+            h.scheme.clone(),
+            h.flexion.map(|f| f.etiquette()),
+            h.conjugaison.as_ref().map(|c| c.etiquette()),
+        )
+    });
+    hypotheses
+}
+
+// Saisie sans espaces, et sa forme nue (lettres seules) pour la superposition
+fn saisie_et_forme_nue(mot: &str) -> (String, String) {
+    let saisie: String = Normalisation::SAISIE
+        .appliquer(mot)
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let nu = sans_diacritiques(&saisie);
+    (saisie, nu)
+}
+
+// Racines possibles pour des radicaux capturés : produit cartésien des
+// lettres possibles pour chacun des n radicaux (voir `alternatives`)
+fn racines_candidates(radicaux: &[Option<char>; 4], n: usize) -> Vec<Racine> {
+    let mut candidats: Vec<Vec<char>> = vec![Vec::new()];
+    for (i, capture) in radicaux.iter().take(n).enumerate() {
+        let precedent = if i > 0 { radicaux[i - 1] } else { None };
+        let mut suivants = Vec::new();
+        for debut in &candidats {
+            for c in alternatives(*capture, precedent) {
+                let mut v = debut.clone();
+                v.push(c);
+                suivants.push(v);
+            }
+        }
+        candidats = suivants;
+    }
+    candidats
+        .into_iter()
+        .filter_map(|lettres| Racine::depuis_lettres(&lettres).ok())
+        .collect()
+}
+
+// Superposer le mot (non fléchi) aux gabarits de tous les schèmes
+fn superposer(mot: &str, table: &SchemeTable, arbre: &Tree) -> Vec<Hypothese> {
+    let (saisie, mot) = saisie_et_forme_nue(mot);
+
+    // Le آ initial peut cacher une hamza radicale suivie d'un ا (آكل = أ + اكل)
+    let mut formes: Vec<Vec<char>> = vec![mot.chars().collect()];
+    if let Some(reste) = mot.strip_prefix('آ') {
+        formes.push(format!("أا{}", reste).chars().collect());
+        formes.push(format!("أأ{}", reste).chars().collect());
+    }
+
+    let mut hypotheses: Vec<Hypothese> = Vec::new();
+
+    for scheme in table.get_all_schemes() {
+        let n = scheme.nombre_radicaux();
+        let mut trouvees: Vec<Hypothese> = Vec::new();
+
+        for forme in &formes {
+            // Le gabarit régulier du schème, puis ses variantes pour les racines faibles
+            let mut gabarits = vec![scheme.gabarit.clone()];
+            gabarits.extend(
+                variantes_possibles(&scheme.id)
+                    .into_iter()
+                    .map(Gabarit::depuis_nom),
+            );
+
+            for gabarit in &gabarits {
+                let Some(radicaux) = extraire_radicaux(forme, gabarit, &scheme.id) else {
+                    continue;
+                };
+
+                for racine in racines_candidates(&radicaux, n) {
+                    // Vérification : la racine doit régénérer exactement le mot
+                    if !equivalent(&saisie, &generer_mot(racine, scheme)) {
+                        continue;
+                    }
+                    if trouvees.iter().any(|h| h.racine == racine) {
+                        continue;
+                    }
+                    trouvees.push(Hypothese {
+                        racine,
+                        scheme: scheme.id.clone(),
+                        dans_arbre: arbre.verify(racine),
+                        flexion: None,
+                        conjugaison: None,
+                    });
+                }
+            }
+        }
+
+        // Garder une seule écriture par racine (ق ر أ / ق ر ء : même clé, voir
+        // Racine::cle), celle de l'arbre si possible
+        trouvees.sort_by_key(|h| !h.dans_arbre);
+        for h in trouvees {
+            let deja = hypotheses
+                .iter()
+                .any(|x| x.scheme == h.scheme && x.racine.cle() == h.racine.cle());
+            if !deja {
+                hypotheses.push(h);
+            }
         }
     }
+
+    hypotheses
+}
+
+// Superposer le mot aux mots conjugués de la forme de chaque schème verbal
+// (يكتبون sur يفعلون), puis chercher chaque racine candidate dans son tableau
+// de conjugaison ; une seule écriture par racine, celle de l'arbre si possible
+fn superposer_conjugaisons(mot: &str, table: &SchemeTable, arbre: &Tree) -> Vec<Hypothese> {
+    let (saisie, mot) = saisie_et_forme_nue(mot);
+    let lettres: Vec<char> = mot.chars().collect();
+    let mut hypotheses: Vec<Hypothese> = Vec::new();
+
+    for scheme in table.get_all_schemes() {
+        let Some(forme) = forme_du_scheme(scheme) else {
+            continue;
+        };
+        let mut racines: Vec<Racine> = Vec::new();
+        for modele in modeles_conjugues(forme) {
+            let gabarit = Gabarit::depuis_nom(modele);
+            let Some(radicaux) = extraire_radicaux(&lettres, &gabarit, &scheme.id) else {
+                continue;
+            };
+            for racine in racines_candidates(&radicaux, forme.nombre_radicaux()) {
+                if !racines.contains(&racine) {
+                    racines.push(racine);
+                }
+            }
+        }
+        racines.sort_by_key(|r| !arbre.verify(*r));
+
+        let mut cles: Vec<Racine> = Vec::new();
+        for racine in racines {
+            let conjugaisons = reconnaitre(&saisie, racine, forme);
+            if conjugaisons.is_empty() || cles.contains(&racine.cle()) {
+                continue;
+            }
+            cles.push(racine.cle());
+            for (_, conjugaison) in conjugaisons {
+                hypotheses.push(Hypothese {
+                    racine,
+                    scheme: scheme.id.clone(),
+                    dans_arbre: arbre.verify(racine),
+                    flexion: None,
+                    conjugaison: Some(conjugaison),
+                });
+            }
+        }
+    }
+
+    hypotheses
+}
+
+// Afficher les hypothèses d'analyse d'un mot (clitiques compris)
+pub fn afficher_analyse(mot: &str, table: &SchemeTable, arbre: &Tree) {
+    let hypotheses = analyser_mot_segmente(mot, table, arbre);
+    if hypotheses.is_empty() {
+        println!("✗ Aucune racine trouvée pour '{}'", mot);
+        return;
+    }
+
+    println!("=== Analyse de {} ===", mot);
+    for a in &hypotheses {
+        let h = &a.hypothese;
+        let marque = if h.dans_arbre { "✓" } else { " " };
+        let flexion = h
+            .flexion
+            .map(|f| f.etiquette())
+            .or_else(|| h.conjugaison.as_ref().map(|c| c.etiquette()))
+            .map(|e| format!(" ({})", e))
+            .unwrap_or_default();
+        println!(
+            "  {} {} : racine {} ({}) — schème {}{}",
+            marque,
+            a.segmentation.etiquette(),
+            h.racine,
+            classifier(&h.racine).nom_arabe(),
+            h.scheme,
+            flexion
+        );
+    }
+    println!(
+        "Total: {} hypothèses (✓ = racine présente dans l'arbre)",
+        hypotheses.len()
+    );
+}
+
+// ========================================================
+// MOTS AVEC CLITIQUES (voir segmentation.rs)
+// ========================================================
+// Un mot de texte (والمكتوب، بكاتبه) n'est reconnu qu'une fois ses clitiques
+// retirés : la validation et l'analyse portent sur la base de chaque découpe
+// proposée par `segmenter`, et la découpe est rendue avec le résultat.
+
+// Une découpe dont la base est validée pour la racine
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidationSegmentee {
+    pub segmentation: Segmentation,
+    pub validation: Validation,
+}
+
+// Valider chaque base candidate ; seules les découpes reconnues sont gardées,
+// la moins segmentée en premier (كاتب seul passe avant ك + اتب)
+// Les lectures que les proclitiques excluent sont écartées : ب + كاتب est
+// un nom (فاعل), pas le verbe كَاتَبَ (فاعل_III)
+pub fn valider_mot_segmente(
+    mot: &str,
+    racine: Racine,
+    table: &SchemeTable,
+) -> Vec<ValidationSegmentee> {
+    segmenter(mot)
+        .into_iter()
+        .filter_map(|segmentation| {
+            let mut validation = valider_mot_complet(&segmentation.base, racine, table);
+            validation
+                .lectures
+                .retain(|l| segmentation.admet(&l.proprietes));
+            validation.valide = !validation.lectures.is_empty();
+            validation.ambigu = validation.lectures.len() > 1;
+            validation.valide.then_some(ValidationSegmentee {
+                segmentation,
+                validation,
+            })
+        })
+        .collect()
+}
+
+// Une hypothèse d'analyse et la découpe dont la base l'a produite
+#[derive(Clone, Debug)]
+pub struct AnalyseSegmentee {
+    pub segmentation: Segmentation,
+    pub hypothese: Hypothese,
+}
+
+// Analyser chaque base candidate (avec le même filtre sur la nature et l'aspect)
+// Classement : racines de l'arbre d'abord, puis découpes avec le moins de
+// clitiques, puis l'ordre de analyser_mot
+pub fn analyser_mot_segmente(
+    mot: &str,
+    table: &SchemeTable,
+    arbre: &Tree,
+) -> Vec<AnalyseSegmentee> {
+    let mut resultats: Vec<AnalyseSegmentee> = Vec::new();
+    for segmentation in segmenter(mot) {
+        for hypothese in analyser_mot(&segmentation.base, table, arbre) {
+            if !segmentation.admet(&hypothese.proprietes(table)) {
+                continue;
+            }
+            resultats.push(AnalyseSegmentee {
+                segmentation: segmentation.clone(),
+                hypothese,
+            });
+        }
+    }
+    // Tri stable : l'ordre des découpes et de analyser_mot est gardé à égalité
+    resultats.sort_by_key(|a| (!a.hypothese.dans_arbre, a.segmentation.nombre_clitiques()));
+    resultats
 }
@@ -0,0 +1,742 @@
+// ============================================================================
+// MOTEUR MORPHOLOGIQUE (objet exporté vers JavaScript)
+// ============================================================================
+// Chaque moteur possède son propre arbre de racines et sa propre table de
+// schèmes : l'application Angular peut donc tenir plusieurs lexiques
+// indépendants (brouillon, version publiée, ...).
+//
+//   const brouillon = new MoteurMorphologique();
+//   brouillon.ajouter_racine('ك', 'ت', 'ب');
+//
+// Les fonctions libres de lib.rs délèguent à un moteur par défaut.
+//
+// Les méthodes qui peuvent échouer retournent `Result<_, MorphoError>` :
+// côté JavaScript, l'erreur est levée comme exception (voir lib.rs).
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::arbre::{self, Tree};
+use crate::conjugaison::{Conjugaison, VoyellesI, conjuguer, forme_depuis_texte};
+use crate::erreur::MorphoError;
+use crate::flexion::Flexion;
+use crate::hashing::{Categorie, Scheme, SchemeTable, init_schemes};
+use crate::lexique::{Lexique, PolitiqueConflit};
+use crate::morpho_analyzer::{
+    Hypothese, analyser_mot, analyser_mot_segmente, generer_et_stocker, generer_famille,
+    generer_mot, generer_par_nom, valider_mot_complet, valider_mot_segmente, valider_mot_vocalise,
+};
+use crate::phonologie::classifier;
+use crate::proprietes::{Criteres, Proprietes};
+use crate::racine::Racine;
+use crate::requete::{LIMITE_PAGE, PageRacines, Pagination};
+use crate::segmentation::Segmentation;
+use crate::texte::{TOP_FREQUENCES, analyser_texte, compter_occurrences};
+
+// ============================================================================
+// STRUCTURES ÉCHANGÉES AVEC JAVASCRIPT
+// ============================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct Derive {
+    pub mot: String,
+    pub schema: String,
+    // Traits fléchis d'un dérivé stocké par validation (كاتبون)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flexion: Option<Flexion>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ValidationResult {
+    pub valide: bool,
+    // Meilleur schème (le plus proche de la saisie) et sa forme vocalisée
+    pub schema: Option<String>,
+    // Forme vocalisée reconnue (la saisie peut être nue)
+    pub forme: Option<String>,
+    // Identifiants de tous les schèmes reconnus, `schema` en premier
+    pub schemes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AnalyseResult {
+    pub racine: String,
+    pub schema: String,
+    pub classe: String,
+    pub dans_arbre: bool,
+    // Désinence retirée pour retrouver la forme du schème (كاتبون)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flexion: Option<Flexion>,
+    // Place dans le tableau de conjugaison du schème verbal (يكتبون)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conjugaison: Option<Conjugaison>,
+    // Découpe en clitiques dont la base a donné l'hypothèse (analyse segmentée)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segmentation: Option<Segmentation>,
+}
+
+impl AnalyseResult {
+    fn depuis(h: Hypothese, segmentation: Option<Segmentation>) -> AnalyseResult {
+        AnalyseResult {
+            racine: h.racine.to_string(),
+            schema: h.scheme,
+            classe: classifier(&h.racine).nom_arabe().to_string(),
+            dans_arbre: h.dans_arbre,
+            flexion: h.flexion,
+            conjugaison: h.conjugaison,
+            segmentation,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SchemeInfo {
+    pub cle: String,
+    pub nom: String,
+    pub pattern: String,
+    pub description: String,
+    pub categorie: Categorie,
+    pub pluriels: Vec<String>,
+    pub proprietes: Proprietes,
+}
+
+impl SchemeInfo {
+    fn depuis(cle: &str, scheme: &Scheme) -> SchemeInfo {
+        SchemeInfo {
+            cle: cle.to_string(),
+            nom: scheme.nom.clone(),
+            pattern: scheme.pattern.clone(),
+            description: scheme.description.clone(),
+            categorie: scheme.categorie,
+            pluriels: scheme.pluriels.clone(),
+            proprietes: scheme.proprietes,
+        }
+    }
+}
+
+// Options d'un schème ajouté depuis JavaScript (toutes facultatives) :
+// { id: "فاعل_III", categorie: "verbe", pluriels: ["فواعل"], proprietes: { forme: 3 } }
+// `id` : clé unique dans la table, le nom par défaut
+#[derive(Default, Serialize, Deserialize)]
+pub struct OptionsScheme {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub categorie: Categorie,
+    #[serde(default)]
+    pub pluriels: Vec<String>,
+    #[serde(default)]
+    pub proprietes: Proprietes,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DeriveInfo {
+    pub mot: String,
+    pub schema: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flexion: Option<Flexion>,
+    // Occurrences dans les textes analysés
+    pub occurrences: u32,
+}
+
+impl DeriveInfo {
+    fn depuis(d: &arbre::Derive) -> DeriveInfo {
+        DeriveInfo {
+            mot: d.mot.clone(),
+            schema: d.schema.clone(),
+            flexion: d.flexion,
+            occurrences: d.occurrences,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RacineInfo {
+    pub racine: String,
+    pub nombre_derives: u32,
+    // Occurrences dans les textes analysés
+    pub frequence: u32,
+    pub derives: Vec<DeriveInfo>,
+}
+
+impl RacineInfo {
+    fn depuis(racine: &Racine, frequence: u32, derives: &[arbre::Derive]) -> RacineInfo {
+        RacineInfo {
+            racine: racine.to_string(),
+            nombre_derives: derives.len() as u32,
+            frequence,
+            derives: derives.iter().map(DeriveInfo::depuis).collect(),
+        }
+    }
+}
+
+// Une page de racines trouvées par intervalle, préfixe ou motif
+#[derive(Serialize, Deserialize)]
+pub struct PageRacinesInfo {
+    // Nombre total de racines trouvées (toutes pages confondues)
+    pub total: usize,
+    pub decalage: usize,
+    pub limite: usize,
+    pub racines: Vec<RacineInfo>,
+}
+
+impl PageRacinesInfo {
+    fn depuis(page: PageRacines) -> PageRacinesInfo {
+        PageRacinesInfo {
+            total: page.total,
+            decalage: page.pagination.decalage,
+            limite: page.pagination.limite,
+            racines: page
+                .racines
+                .iter()
+                .map(|n| RacineInfo::depuis(&n.racine, n.frequence, &n.derives))
+                .collect(),
+        }
+    }
+}
+
+// Pagination reçue de JavaScript : 50 racines à partir de la première par défaut
+fn pagination(decalage: Option<usize>, limite: Option<usize>) -> Pagination {
+    Pagination {
+        decalage: decalage.unwrap_or(0),
+        limite: limite.unwrap_or(LIMITE_PAGE),
+    }
+}
+
+// Un dérivé stocké et sa racine (dérivés les plus fréquents)
+#[derive(Serialize, Deserialize)]
+pub struct DeriveFrequent {
+    pub racine: String,
+    pub mot: String,
+    pub schema: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flexion: Option<Flexion>,
+    pub occurrences: u32,
+}
+
+// ============================================================================
+// LE MOTEUR
+// ============================================================================
+
+#[wasm_bindgen]
+pub struct MoteurMorphologique {
+    arbre: Tree,
+    schemes: SchemeTable,
+}
+
+impl Default for MoteurMorphologique {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Accès direct aux structures (côté Rust uniquement)
+impl MoteurMorphologique {
+    pub fn arbre(&self) -> &Tree {
+        &self.arbre
+    }
+
+    pub fn schemes(&self) -> &SchemeTable {
+        &self.schemes
+    }
+}
+
+#[wasm_bindgen]
+impl MoteurMorphologique {
+    // Nouveau lexique : arbre vide + schèmes par défaut
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> MoteurMorphologique {
+        MoteurMorphologique {
+            arbre: Tree::new(),
+            schemes: init_schemes(),
+        }
+    }
+
+    // ========================================================================
+    // GESTION DES RACINES
+    // ========================================================================
+
+    // Chaque radical doit être une consonne arabe (LettreNonArabe sinon)
+    pub fn ajouter_racine(
+        &mut self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<String, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        self.arbre.insert(racine);
+        Ok(format!("✓ Racine '{}' ajoutée", racine))
+    }
+
+    pub fn chercher_racine(
+        &self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<bool, MorphoError> {
+        Ok(self.arbre.verify(Racine::depuis_radicaux(c1, c2, c3, c4)?))
+    }
+
+    pub fn supprimer_racine(
+        &mut self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<(), MorphoError> {
+        self.arbre.delete(Racine::depuis_radicaux(c1, c2, c3, c4)?)
+    }
+
+    // Une racine par ligne ; une ligne invalide annule tout le chargement
+    // Retourne le nombre de racines ajoutées (les doublons ne comptent pas)
+    pub fn charger_racines_depuis_texte(&mut self, contenu: &str) -> Result<u32, MorphoError> {
+        self.arbre.charger_depuis_texte(contenu)
+    }
+
+    // ========================================================================
+    // GÉNÉRATION DE DÉRIVÉS
+    // ========================================================================
+
+    pub fn generer_derive(
+        &self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+        schema: &str,
+    ) -> Result<String, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        generer_par_nom(racine, schema, &self.schemes)
+    }
+
+    // Classe morphologique de la racine (صحيح، مثال، أجوف، ناقص، لفيف، مضعف، مهموز)
+    pub fn classe_racine(
+        &self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<String, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        Ok(classifier(&racine).nom_arabe().to_string())
+    }
+
+    pub fn generer_tous_derives(
+        &self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<JsValue, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        let resultats: Vec<Derive> = self
+            .schemes
+            .get_all_schemes()
+            .into_iter()
+            .filter(|scheme| scheme.compatible_avec(&racine))
+            .map(|scheme| Derive {
+                mot: generer_mot(racine, scheme),
+                schema: scheme.id.clone(),
+                flexion: None,
+            })
+            .collect();
+
+        Ok(serde_wasm_bindgen::to_value(&resultats)?)
+    }
+
+    // Famille regroupée par catégorie, avec les pluriels brisés des singuliers
+    // (voir MembreFamille dans morpho_analyzer.rs)
+    pub fn generer_famille(
+        &self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<JsValue, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        let famille = generer_famille(racine, &self.schemes);
+        Ok(serde_wasm_bindgen::to_value(&famille)?)
+    }
+
+    pub fn ajouter_derive_a_racine(
+        &mut self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+        mot: &str,
+        schema: &str,
+    ) -> Result<(), MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        self.arbre
+            .ajouter_derive(racine, mot.to_string(), schema.to_string())
+    }
+
+    // Générer un dérivé ET le stocker automatiquement (comme le terminal)
+    // Retourne le mot généré
+    pub fn generer_et_stocker_derive(
+        &mut self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+        schema: &str,
+    ) -> Result<String, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+
+        // Vérifier que la racine existe avant de générer
+        self.arbre.noeud(racine)?;
+        let mot = generer_par_nom(racine, schema, &self.schemes)?;
+        // Le dérivé est stocké avec l'identifiant du schème, pas le modèle saisi
+        let id = self.schemes.trouver(schema)?.id.clone();
+        self.arbre.ajouter_derive(racine, mot.clone(), id)?;
+        Ok(mot)
+    }
+
+    // Générer TOUS les dérivés ET les stocker automatiquement (comme le terminal)
+    pub fn generer_et_stocker_tous_derives(
+        &mut self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<u32, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        generer_et_stocker(&mut self.arbre, racine, &self.schemes)
+    }
+
+    // ========================================================================
+    // CONJUGAISON
+    // ========================================================================
+
+    // Tableau de conjugaison (voir conjugaison.rs pour la structure retournée)
+    // `forme` : "I" à "X", "QI", "QII", un modèle (استفعل) ou une clé de schème
+    // `voyelles` : باب de la forme I ("a-u", "a-i", "i-a"...), déduit sinon
+    pub fn conjuguer(
+        &self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+        forme: &str,
+        voyelles: Option<String>,
+    ) -> Result<JsValue, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        let forme = forme_depuis_texte(forme, &self.schemes)?;
+        let voyelles = match voyelles {
+            Some(texte) => Some(VoyellesI::depuis_texte(&texte)?),
+            None => None,
+        };
+        let table = conjuguer(racine, forme, voyelles)?;
+        Ok(serde_wasm_bindgen::to_value(&table)?)
+    }
+
+    // ========================================================================
+    // VALIDATION ET ANALYSE
+    // ========================================================================
+
+    pub fn valider_mot_derive(
+        &self,
+        mot: &str,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<JsValue, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        let trouves = valider_mot_vocalise(mot, racine, &self.schemes);
+        let resultat = ValidationResult {
+            valide: !trouves.is_empty(),
+            schema: trouves.first().map(|(schema, _)| schema.clone()),
+            forme: trouves.first().map(|(_, forme)| forme.clone()),
+            schemes: trouves.into_iter().map(|(schema, _)| schema).collect(),
+        };
+        Ok(serde_wasm_bindgen::to_value(&resultat)?)
+    }
+
+    // Toutes les lectures du mot avec les métadonnées de leur schème et le
+    // drapeau `ambigu` (voir Validation dans morpho_analyzer.rs)
+    pub fn valider_mot_complet(
+        &self,
+        mot: &str,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<JsValue, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        let validation = valider_mot_complet(mot, racine, &self.schemes);
+        Ok(serde_wasm_bindgen::to_value(&validation)?)
+    }
+
+    // Validation après retrait des clitiques : une entrée par découpe reconnue
+    pub fn valider_mot_segmente(
+        &self,
+        mot: &str,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<JsValue, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        let resultats = valider_mot_segmente(mot, racine, &self.schemes);
+        Ok(serde_wasm_bindgen::to_value(&resultats)?)
+    }
+
+    // Retourne toutes les hypothèses (racine, schème), racines de l'arbre en premier
+    pub fn analyser_mot_derive(&self, mot: &str) -> Result<JsValue, MorphoError> {
+        let resultats: Vec<AnalyseResult> = analyser_mot(mot, &self.schemes, &self.arbre)
+            .into_iter()
+            .map(|h| AnalyseResult::depuis(h, None))
+            .collect();
+
+        Ok(serde_wasm_bindgen::to_value(&resultats)?)
+    }
+
+    // Analyse de chaque base après retrait des clitiques (والمكتوب → مكتوب)
+    pub fn analyser_mot_segmente(&self, mot: &str) -> Result<JsValue, MorphoError> {
+        let resultats: Vec<AnalyseResult> = analyser_mot_segmente(mot, &self.schemes, &self.arbre)
+            .into_iter()
+            .map(|a| AnalyseResult::depuis(a.hypothese, Some(a.segmentation)))
+            .collect();
+
+        Ok(serde_wasm_bindgen::to_value(&resultats)?)
+    }
+
+    // Analyse de chaque mot d'un texte et statistiques (voir texte.rs)
+    // `top` : nombre de racines et de schèmes les plus fréquents (10 par défaut)
+    // Les jetons reconnus sont comptés dans les fréquences de l'arbre
+    pub fn analyser_texte(
+        &mut self,
+        texte: &str,
+        top: Option<usize>,
+    ) -> Result<JsValue, MorphoError> {
+        let analyse = analyser_texte(
+            texte,
+            &self.schemes,
+            &self.arbre,
+            top.unwrap_or(TOP_FREQUENCES),
+        );
+        compter_occurrences(&analyse, &self.schemes, &mut self.arbre);
+        Ok(serde_wasm_bindgen::to_value(&analyse)?)
+    }
+
+    // ========================================================================
+    // DÉRIVÉS STOCKÉS
+    // ========================================================================
+
+    pub fn obtenir_derives_stockes(
+        &mut self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<JsValue, MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        let derives: Vec<DeriveInfo> = self
+            .arbre
+            .noeud(racine)?
+            .derives
+            .iter()
+            .map(DeriveInfo::depuis)
+            .collect();
+
+        Ok(serde_wasm_bindgen::to_value(&derives)?)
+    }
+
+    pub fn supprimer_derive(
+        &mut self,
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+        mot: &str,
+    ) -> Result<(), MorphoError> {
+        let racine = Racine::depuis_radicaux(c1, c2, c3, c4)?;
+        self.arbre.supprimer_derive(racine, mot)
+    }
+
+    // ========================================================================
+    // GESTION DES SCHÈMES
+    // ========================================================================
+
+    pub fn obtenir_tous_schemes(&self) -> Result<JsValue, MorphoError> {
+        let infos: Vec<SchemeInfo> = self
+            .schemes
+            .filtrer(&Criteres::default())
+            .into_iter()
+            .map(|(cle, s)| SchemeInfo::depuis(cle, s))
+            .collect();
+
+        Ok(serde_wasm_bindgen::to_value(&infos)?)
+    }
+
+    // Schèmes qui satisfont des critères (voir Criteres dans proprietes.rs) :
+    // { proprietes: { forme: 4, nature: "verbe" }, categorie: "verbe", radicaux: 3 }
+    pub fn filtrer_schemes(&self, criteres: JsValue) -> Result<JsValue, MorphoError> {
+        let criteres: Criteres = serde_wasm_bindgen::from_value(criteres)
+            .map_err(|e| MorphoError::Json(e.to_string()))?;
+        let infos: Vec<SchemeInfo> = self
+            .schemes
+            .filtrer(&criteres)
+            .into_iter()
+            .map(|(cle, s)| SchemeInfo::depuis(cle, s))
+            .collect();
+
+        Ok(serde_wasm_bindgen::to_value(&infos)?)
+    }
+
+    // `options` : identifiant, catégorie, pluriels et propriétés (OptionsScheme),
+    // ou undefined ; retourne l'identifiant du schème ajouté
+    // Erreur si le pattern est mal formé (voir gabarit.rs pour la syntaxe), si
+    // l'identifiant est déjà pris (SchemeExistant) ou si la table des schèmes
+    // ne peut plus grandir
+    pub fn ajouter_scheme(
+        &mut self,
+        nom: &str,
+        pattern: &str,
+        description: &str,
+        options: JsValue,
+    ) -> Result<String, MorphoError> {
+        let options: Option<OptionsScheme> = serde_wasm_bindgen::from_value(options)
+            .map_err(|e| MorphoError::Json(e.to_string()))?;
+        let options = options.unwrap_or_default();
+        let id = options.id.unwrap_or_else(|| nom.to_string());
+        let pluriels: Vec<&str> = options.pluriels.iter().map(|p| p.as_str()).collect();
+        let scheme = Scheme::new(nom, pattern, description)?
+            .avec_categorie(options.categorie)
+            .avec_pluriels(&pluriels)
+            .avec_proprietes(options.proprietes);
+        self.schemes.try_insert(id.clone(), scheme)?;
+        Ok(id)
+    }
+
+    pub fn supprimer_scheme(&mut self, nom: &str) -> Result<(), MorphoError> {
+        self.schemes.delete(nom)
+    }
+
+    // ========================================================================
+    // AFFICHAGE DE L'ARBRE
+    // ========================================================================
+
+    pub fn obtenir_toutes_racines(&self) -> Result<JsValue, MorphoError> {
+        let mut racines = Vec::new();
+        self.arbre.collecter_racines(&mut racines);
+
+        let infos: Vec<RacineInfo> = racines
+            .iter()
+            .map(|(racine, frequence, derives)| RacineInfo::depuis(racine, *frequence, derives))
+            .collect();
+
+        Ok(serde_wasm_bindgen::to_value(&infos)?)
+    }
+
+    // Racines entre deux bornes comprises ("ك" .. "م"), bornes facultatives
+    pub fn racines_entre(
+        &self,
+        debut: Option<String>,
+        fin: Option<String>,
+        decalage: Option<usize>,
+        limite: Option<usize>,
+    ) -> Result<JsValue, MorphoError> {
+        let page = self.arbre.racines_entre(
+            debut.as_deref(),
+            fin.as_deref(),
+            pagination(decalage, limite),
+        )?;
+        Ok(serde_wasm_bindgen::to_value(&PageRacinesInfo::depuis(
+            page,
+        ))?)
+    }
+
+    // Racines qui commencent par ces lettres ("ك ت")
+    pub fn racines_prefixe(
+        &self,
+        prefixe: &str,
+        decalage: Option<usize>,
+        limite: Option<usize>,
+    ) -> Result<JsValue, MorphoError> {
+        let page = self
+            .arbre
+            .racines_prefixe(prefixe, pagination(decalage, limite))?;
+        Ok(serde_wasm_bindgen::to_value(&PageRacinesInfo::depuis(
+            page,
+        ))?)
+    }
+
+    // Racines qui correspondent à un motif ("? ت ب" : كتب، رتب...)
+    pub fn racines_motif(
+        &self,
+        motif: &str,
+        decalage: Option<usize>,
+        limite: Option<usize>,
+    ) -> Result<JsValue, MorphoError> {
+        let page = self
+            .arbre
+            .racines_motif(motif, pagination(decalage, limite))?;
+        Ok(serde_wasm_bindgen::to_value(&PageRacinesInfo::depuis(
+            page,
+        ))?)
+    }
+
+    // Les `n` racines les plus fréquentes dans les textes analysés
+    pub fn racines_frequentes(&self, n: usize) -> Result<JsValue, MorphoError> {
+        let infos: Vec<RacineInfo> = self
+            .arbre
+            .racines_frequentes(n)
+            .iter()
+            .map(|(racine, frequence, derives)| RacineInfo::depuis(racine, *frequence, derives))
+            .collect();
+
+        Ok(serde_wasm_bindgen::to_value(&infos)?)
+    }
+
+    // Les `n` dérivés stockés les plus fréquents dans les textes analysés
+    pub fn derives_frequents(&self, n: usize) -> Result<JsValue, MorphoError> {
+        let derives: Vec<DeriveFrequent> = self
+            .arbre
+            .derives_frequents(n)
+            .iter()
+            .map(|(racine, d)| DeriveFrequent {
+                racine: racine.to_string(),
+                mot: d.mot.clone(),
+                schema: d.schema.clone(),
+                flexion: d.flexion,
+                occurrences: d.occurrences,
+            })
+            .collect();
+
+        Ok(serde_wasm_bindgen::to_value(&derives)?)
+    }
+
+    // ========================================================================
+    // SAUVEGARDE ET RESTAURATION (pour éviter la perte lors du rafraîchissement)
+    // ========================================================================
+
+    // Exporter toutes les données en JSON (pour localStorage)
+    // Toutes les racines (même sans dérivés), la table des schèmes et les
+    // métadonnées d'export : voir lexique.rs pour le format versionné
+    pub fn exporter_donnees(&self) -> String {
+        Lexique::depuis(&self.arbre, &self.schemes).vers_json_compact()
+    }
+
+    // Fusionner un export (d'un collègue, d'un autre onglet...) dans ce moteur
+    // sans rien effacer ; retourne le RapportFusion (voir lexique.rs)
+    pub fn fusionner_donnees(
+        &mut self,
+        json: &str,
+        politique: PolitiqueConflit,
+    ) -> Result<JsValue, MorphoError> {
+        let rapport =
+            Lexique::depuis_json(json)?.fusionner(&mut self.arbre, &mut self.schemes, politique)?;
+        Ok(serde_wasm_bindgen::to_value(&rapport)?)
+    }
+
+    // Importer les données depuis JSON (localStorage ou lexique du terminal)
+    // Remplace le contenu de l'arbre de ce moteur, et sa table des schèmes si
+    // le JSON en contient (voir lexique.rs pour les formats acceptés)
+    pub fn importer_donnees(&mut self, json: &str) -> Result<(), MorphoError> {
+        Lexique::depuis_json(json)?.appliquer(&mut self.arbre, &mut self.schemes)
+    }
+}
@@ -0,0 +1,290 @@
+// ============================================================================
+// NORMALISATION DU TEXTE ARABE
+// ============================================================================
+// Un même mot peut arriver sous plusieurs écritures : أكل / اكل, رمى / رمي,
+// مكاتبة / مكاتبه, كـتـب (tatweel), ﻛﺘﺐ (formes de présentation copiées
+// depuis un PDF), ا + ٔ au lieu de أ (forme décomposée)...
+//
+// `Normalisation` regroupe les règles activables ; chaque usage a son préréglage :
+//   SAISIE      → nettoyage sans perte de sens (toute saisie utilisateur)
+//   RACINE      → clé de comparaison des racines dans l'arbre
+//   CLE_SCHEME  → clé de recherche dans la table des schèmes
+//   COMPARAISON → validation d'un mot contre une forme générée
+//
+// La forme brute (après SAISIE) reste celle qui est stockée et affichée :
+// les autres préréglages ne servent qu'à comparer.
+
+use crate::gabarit::{SHADDA, correspond, est_diacritique};
+
+// Tatweel (kashida) : allongement purement graphique
+pub const TATWEEL: char = 'ـ';
+
+// Alif suscrit (هٰذا) : traité comme une voyelle
+const ALIF_SUSCRIT: char = '\u{0670}';
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Normalisation {
+    // Composition NFC (ا + ٔ → أ), formes de présentation (ﻙ → ك, ﻻ → لا)
+    // et suppression des diacritiques isolés ou répétés
+    pub formes_unicode: bool,
+    // Supprimer le tatweel (كـتـب → كتب)
+    pub tatweel: bool,
+    // Supprimer voyelles brèves, sukun, tanwin et alif suscrit
+    pub voyelles: bool,
+    // Supprimer la shadda
+    pub shadda: bool,
+    // أ إ آ ٱ → ا
+    pub alifs: bool,
+    // Toutes les hamzas (ؤ ئ, et أ إ آ si `alifs` est désactivé) → ء
+    pub hamzas: bool,
+    // ى → ي
+    pub alif_maqsura: bool,
+    // ة → ه
+    pub ta_marbuta: bool,
+}
+
+impl Default for Normalisation {
+    fn default() -> Self {
+        Normalisation::SAISIE
+    }
+}
+
+impl Normalisation {
+    // Aucune règle : le texte est rendu tel quel
+    pub const AUCUNE: Normalisation = Normalisation {
+        formes_unicode: false,
+        tatweel: false,
+        voyelles: false,
+        shadda: false,
+        alifs: false,
+        hamzas: false,
+        alif_maqsura: false,
+        ta_marbuta: false,
+    };
+
+    // Nettoyage de toute saisie : ne change ni les lettres ni les voyelles voulues
+    pub const SAISIE: Normalisation = Normalisation {
+        formes_unicode: true,
+        tatweel: true,
+        ..Normalisation::AUCUNE
+    };
+
+    // Racines : أ ك ل = ء ك ل, ر م ى = ر م ي ; une racine n'a pas de voyelles
+    pub const RACINE: Normalisation = Normalisation {
+        voyelles: true,
+        shadda: true,
+        hamzas: true,
+        alif_maqsura: true,
+        ta_marbuta: true,
+        ..Normalisation::SAISIE
+    };
+
+    // Clés de schèmes : افعل trouve أفعل, مفاعله trouve مفاعلة
+    // La shadda est gardée : فعّل et فعل sont deux schèmes différents
+    pub const CLE_SCHEME: Normalisation = Normalisation {
+        voyelles: true,
+        alifs: true,
+        alif_maqsura: true,
+        ta_marbuta: true,
+        ..Normalisation::SAISIE
+    };
+
+    // Validation : اكل ≈ أَكَلَ, مسئول ≈ مسؤول ; les voyelles sont gardées pour
+    // être comparées au niveau de détail de la saisie (voir `equivalent`)
+    pub const COMPARAISON: Normalisation = Normalisation {
+        alifs: true,
+        hamzas: true,
+        alif_maqsura: true,
+        ta_marbuta: true,
+        ..Normalisation::SAISIE
+    };
+
+    // Appliquer les règles à un texte
+    pub fn appliquer(&self, texte: &str) -> String {
+        let mut resultat = String::with_capacity(texte.len());
+
+        for c in texte.chars() {
+            if self.formes_unicode {
+                // Forme de présentation → lettre(s) de base
+                if let Some(base) = forme_presentation(c) {
+                    for b in base.chars() {
+                        self.pousser(&mut resultat, b);
+                    }
+                    continue;
+                }
+            }
+            self.pousser(&mut resultat, c);
+        }
+        resultat
+    }
+
+    // Normaliser une lettre isolée (radical d'une racine)
+    // Une ligature (ﻻ) n'est pas une lettre : elle est laissée telle quelle
+    pub fn lettre(&self, c: char) -> char {
+        let c = match forme_presentation(c) {
+            Some(base) if self.formes_unicode && base.chars().count() == 1 => {
+                base.chars().next().unwrap_or(c)
+            }
+            _ => c,
+        };
+        self.replier(c)
+    }
+
+    // Ajouter un caractère au résultat en appliquant les règles
+    fn pousser(&self, resultat: &mut String, c: char) {
+        if self.tatweel && c == TATWEEL {
+            return;
+        }
+        // Forme décomposée : la hamza ou la madda se combine à sa lettre
+        if self.formes_unicode
+            && let Some(derniere) = resultat.chars().last()
+            && let Some(composee) = composer(derniere, c)
+        {
+            resultat.pop();
+            resultat.push(self.replier(composee));
+            return;
+        }
+        if est_marque(c) {
+            if self.voyelles && c != SHADDA {
+                return;
+            }
+            if self.shadda && c == SHADDA {
+                return;
+            }
+            // Diacritique isolé (sans lettre) ou déjà présent sur la lettre
+            if self.formes_unicode && !marque_attachee(resultat, c) {
+                return;
+            }
+            resultat.push(c);
+            return;
+        }
+        resultat.push(self.replier(c));
+    }
+
+    // Règles lettre à lettre
+    fn replier(&self, c: char) -> char {
+        match c {
+            'أ' | 'إ' | 'آ' | 'ٱ' if self.alifs => 'ا',
+            'أ' | 'إ' | 'آ' | 'ؤ' | 'ئ' if self.hamzas => 'ء',
+            'ى' if self.alif_maqsura => 'ي',
+            'ة' if self.ta_marbuta => 'ه',
+            c => c,
+        }
+    }
+}
+
+// Voyelles, sukun, tanwin, shadda et alif suscrit
+fn est_marque(c: char) -> bool {
+    est_diacritique(c) || c == SHADDA || c == ALIF_SUSCRIT
+}
+
+// Le diacritique peut-il s'ajouter à la fin du texte ? Il faut une lettre juste
+// avant (éventuellement suivie d'autres diacritiques), qui ne le porte pas déjà
+fn marque_attachee(texte: &str, marque: char) -> bool {
+    for c in texte.chars().rev() {
+        if c == marque {
+            return false;
+        }
+        if !est_marque(c) {
+            return c.is_alphabetic();
+        }
+    }
+    false
+}
+
+// Compositions canoniques (NFC) de l'arabe : lettre + hamza/madda combinante
+fn composer(lettre: char, marque: char) -> Option<char> {
+    match (lettre, marque) {
+        ('ا', '\u{0653}') => Some('آ'),
+        ('ا', '\u{0654}') => Some('أ'),
+        ('ا', '\u{0655}') => Some('إ'),
+        ('و', '\u{0654}') => Some('ؤ'),
+        ('ي', '\u{0654}') => Some('ئ'),
+        ('\u{06D5}', '\u{0654}') => Some('\u{06C0}'),
+        ('\u{06C1}', '\u{0654}') => Some('\u{06C2}'),
+        ('\u{06D2}', '\u{0654}') => Some('\u{06D3}'),
+        _ => None,
+    }
+}
+
+// Formes de présentation B (U+FE80..U+FEFC), dans l'ordre du bloc Unicode :
+// chaque lettre occupe 1, 2 (isolée, finale) ou 4 (isolée, finale, initiale,
+// médiane) cases
+const FORMES_PRESENTATION: [(&str, u32); 40] = [
+    ("ء", 1),
+    ("آ", 2),
+    ("أ", 2),
+    ("ؤ", 2),
+    ("إ", 2),
+    ("ئ", 4),
+    ("ا", 2),
+    ("ب", 4),
+    ("ة", 2),
+    ("ت", 4),
+    ("ث", 4),
+    ("ج", 4),
+    ("ح", 4),
+    ("خ", 4),
+    ("د", 2),
+    ("ذ", 2),
+    ("ر", 2),
+    ("ز", 2),
+    ("س", 4),
+    ("ش", 4),
+    ("ص", 4),
+    ("ض", 4),
+    ("ط", 4),
+    ("ظ", 4),
+    ("ع", 4),
+    ("غ", 4),
+    ("ف", 4),
+    ("ق", 4),
+    ("ك", 4),
+    ("ل", 4),
+    ("م", 4),
+    ("ن", 4),
+    ("ه", 4),
+    ("و", 2),
+    ("ى", 2),
+    ("ي", 4),
+    ("لآ", 2),
+    ("لأ", 2),
+    ("لإ", 2),
+    ("لا", 2),
+];
+
+// Lettre(s) de base d'une forme de présentation, None pour un autre caractère
+fn forme_presentation(c: char) -> Option<&'static str> {
+    // U+064B..U+0652 : fathatan, dammatan, kasratan, fatha, damma, kasra, shadda, sukun
+    const VOYELLES: [&str; 8] = [
+        "\u{064B}", "\u{064C}", "\u{064D}", "\u{064E}", "\u{064F}", "\u{0650}", "\u{0651}",
+        "\u{0652}",
+    ];
+
+    let code = c as u32;
+    match code {
+        // Voyelles isolées ou posées sur un tatweel (U+FE70..U+FE7F)
+        0xFE70..=0xFE7F if code != 0xFE73 && code != 0xFE75 => {
+            Some(VOYELLES[((code - 0xFE70) / 2) as usize])
+        }
+        0xFE80..=0xFEFC => {
+            let mut debut = 0xFE80;
+            for (base, nombre) in FORMES_PRESENTATION {
+                if code < debut + nombre {
+                    return Some(base);
+                }
+                debut += nombre;
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+// Une saisie et une forme générée désignent-elles le même mot ?
+// Les deux sont repliées (COMPARAISON), puis comparées au niveau de détail de
+// la saisie : اكل, أكل, أَكَلَ correspondent à أَكَلَ ; أُكِلَ non
+pub fn equivalent(saisie: &str, forme: &str) -> bool {
+    let regles = Normalisation::COMPARAISON;
+    correspond(&regles.appliquer(saisie), &regles.appliquer(forme))
+}
@@ -0,0 +1,523 @@
+// ============================================================================
+// RACINES FAIBLES : classification et ajustements phonologiques
+// ============================================================================
+// La substitution ف/ع/ل → radicaux ne suffit pas quand la racine contient
+// une lettre faible (و، ي), une hamza, ou deux radicaux identiques :
+//   ق و ل + فاعل   → قاول  ✗   قائل  ✓  (أجوف)
+//   ر م ي + مفعول  → مرموي ✗   مرمي  ✓  (ناقص)
+//   و ع د + افتعل  → اوتعد ✗   اتّعد ✓  (مثال)
+//
+// Principe : selon la classe de la racine, le gabarit de certains schèmes est
+// remplacé par une VARIANTE (un autre modèle ف/ع/ل) avant le remplissage,
+// puis l'orthographe des hamzas radicales est corrigée selon leur contexte.
+
+use crate::gabarit::{SHADDA, est_diacritique};
+use crate::racine::Racine;
+
+// Voyelles brèves et sukun (absence de voyelle)
+const FATHA: char = '\u{064E}';
+const DAMMA: char = '\u{064F}';
+const KASRA: char = '\u{0650}';
+const SUKUN: char = '\u{0652}';
+
+// Classe morphologique d'une racine trilitère
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ClasseRacine {
+    Saine,       // صحيح   : aucune lettre faible (ك ت ب)
+    Assimilee,   // مثال   : 1er radical faible (و ع د)
+    Creuse,      // أجوف   : 2ème radical faible (ق و ل)
+    Defectueuse, // ناقص   : 3ème radical faible (ر م ي)
+    Lafif,       // لفيف   : deux radicaux faibles (و ق ي، ط و ي)
+    Geminee,     // مضعف   : 2ème et 3ème radicaux identiques (م د د)
+    Hamzee,      // مهموز  : contient une hamza (أ ك ل، س أ ل، ق ر أ)
+}
+
+impl ClasseRacine {
+    // Nom arabe traditionnel de la classe
+    pub fn nom_arabe(&self) -> &'static str {
+        match self {
+            ClasseRacine::Saine => "صحيح",
+            ClasseRacine::Assimilee => "مثال",
+            ClasseRacine::Creuse => "أجوف",
+            ClasseRacine::Defectueuse => "ناقص",
+            ClasseRacine::Lafif => "لفيف",
+            ClasseRacine::Geminee => "مضعف",
+            ClasseRacine::Hamzee => "مهموز",
+        }
+    }
+}
+
+// Lettres faibles (حروف العلة) utilisées comme radicaux
+pub fn est_faible(c: char) -> bool {
+    c == 'و' || c == 'ي' || c == 'ى'
+}
+
+// Toutes les formes de la hamza
+pub fn est_hamza(c: char) -> bool {
+    matches!(c, 'ء' | 'أ' | 'إ' | 'ؤ' | 'ئ' | 'آ')
+}
+
+// Classer une racine
+// Priorité : lettres faibles > gémination > hamza > saine
+// (une racine comme أ ت ي est classée ناقص ; sa hamza est tout de même
+// orthographiée correctement par `orthographier_hamzas`)
+pub fn classifier(racine: &Racine) -> ClasseRacine {
+    // Les quadrilitères se comportent comme des racines saines
+    if racine.est_quadrilitere() {
+        if racine.lettres().iter().any(|c| est_hamza(*c)) {
+            return ClasseRacine::Hamzee;
+        }
+        return ClasseRacine::Saine;
+    }
+
+    let r = racine.lettres();
+    let (f1, f2, f3) = (est_faible(r[0]), est_faible(r[1]), est_faible(r[2]));
+
+    if (f1 || f2) && f3 {
+        ClasseRacine::Lafif
+    } else if f1 {
+        ClasseRacine::Assimilee
+    } else if f2 {
+        ClasseRacine::Creuse
+    } else if f3 {
+        ClasseRacine::Defectueuse
+    } else if r[1] == r[2] {
+        ClasseRacine::Geminee
+    } else if r.iter().any(|c| est_hamza(*c)) {
+        ClasseRacine::Hamzee
+    } else {
+        ClasseRacine::Saine
+    }
+}
+
+// ========================================================
+// VARIANTES DE SCHÈMES PAR CLASSE
+// ========================================================
+// Chaque variante est un modèle ف/ع/ل vocalisé écrit d'un seul bloc, transformé
+// en gabarit par `Gabarit::depuis_nom`. Un schème absent de ces tables garde son
+// gabarit régulier (ex: قوّل, قاول, مقاولة pour une racine creuse).
+// Les tables sont indexées par l'identifiant du schème (sa clé dans la table) :
+// فاعل (participe, قائل) et فاعل_III (verbe, قاول) ont la même forme mais pas
+// les mêmes variantes.
+
+// أجوف : le radical faible disparaît ou devient ا / ئ / ي
+fn variante_creuse(racine: &Racine, scheme_id: &str) -> Option<&'static str> {
+    match scheme_id {
+        "فعل" => Some("فَالَ"),   // قال، باع
+        "فاعل" => Some("فَائِل"), // قائل، بائع
+        "مفعول" => {
+            if racine.lettres()[1] == 'و' {
+                Some("مَفُول") // مقول
+            } else {
+                Some("مَفِيل") // مبيع
+            }
+        }
+        "أفعل" => Some("أَفَالَ"),     // أقام
+        "إفعال" => Some("إِفَالَة"),   // إقامة
+        "افتعل" => Some("افْتَالَ"),   // اختار
+        "مفتعل" => Some("مُفْتَال"),   // مختار
+        "استفعل" => Some("اسْتَفَالَ"), // استقام
+        "مستفعل" => Some("مُسْتَفِيل"), // مستقيم
+        "فاعلة" => Some("فَائِلَة"),   // قائلة
+        "مفعل" => Some("مَفَال"),     // مقام، مطار
+        "مفعلة" => Some("مَفَالَة"),   // مقالة
+        "فعال" => Some("فِيَال"),     // ثياب، ديار
+        "فواعل" => Some("فَوَائِل"),   // قوائل
+        _ => None,
+    }
+}
+
+// ناقص : le 3ème radical devient ى (ou ا pour فعل en و), ي, ة ou disparaît
+fn variante_defectueuse(racine: &Racine, scheme_id: &str) -> Option<&'static str> {
+    let en_waw = racine.lettres()[2] == 'و';
+    match scheme_id {
+        "فعل" => {
+            if en_waw {
+                Some("فَعَا") // دعا
+            } else {
+                Some("فَعَى") // رمى
+            }
+        }
+        "فاعل" => Some("فَاعٍ"),      // رامٍ
+        "فاعل_III" => Some("فَاعَى"), // نادى، رامى
+        "مفعول" => {
+            if en_waw {
+                Some("مَفْعُوّ") // مدعوّ
+            } else {
+                Some("مَفْعِيّ") // مرميّ
+            }
+        }
+        "فعّل" => Some("فَعَّى"),       // ربّى
+        "تفعيل" => Some("تَفْعِيَة"),   // تربية
+        "مفعّل" => Some("مُفَعٍّ"),      // مربٍّ
+        "مفاعلة" => Some("مُفَاعَاة"), // مناداة
+        "أفعل" => Some("أَفْعَى"),     // أعطى
+        "إفعال" => Some("إِفْعَاء"),   // إعطاء
+        "تفعّل" => Some("تَفَعَّى"),     // تلقّى
+        "افتعل" => Some("افْتَعَى"),   // ارتمى
+        "مفتعل" => Some("مُفْتَعٍ"),    // مرتمٍ
+        "استفعل" => Some("اسْتَفْعَى"), // استرضى
+        "مستفعل" => Some("مُسْتَفْعٍ"),  // مسترضٍ
+        "فاعلة" => Some("فَاعِيَة"),   // رامية، داعية
+        "فعيل" => Some("فَعِيّ"),      // غنيّ، عليّ
+        "مفعل" => Some("مَفْعًى"),     // مرمًى، ملهًى
+        "مفعلة" => Some("مَفْعَاة"),   // مرقاة
+        "أفعال" => Some("أَفْعَاء"),   // أعداء، أسماء
+        "فعلاء" => Some("أَفْعِيَاء"),   // أغنياء، أولياء
+        "فعال" => Some("فِعَاء"),     // دلاء، ظباء
+        "فعّال" => Some("فُعَاة"),     // رماة، قضاة (pluriel de فاعل)
+        "فعول" => Some("فُعِيّ"),      // دليّ
+        "مفعال" => Some("مِفْعَاء"),   // معطاء
+        "فواعل" => Some("فَوَاعٍ"),    // دواعٍ
+        "مفاعل" => Some("مَفَاعٍ"),    // مرامٍ، ملاهٍ
+        "مفاعيل" => Some("مَفَاعِيّ"),  // مراميّ
+        _ => None,
+    }
+}
+
+// مثال : le و/ي initial s'assimile au ت de la forme VIII, devient ي après kasra
+// Deux و en tête de mot : le premier devient أ (أواعد)
+fn variante_assimilee(racine: &Racine, scheme_id: &str) -> Option<&'static str> {
+    match scheme_id {
+        "افتعل" => Some("اتَّعَلَ"),                                // اتّعد
+        "مفتعل" => Some("مُتَّعِل"),                                // متّعد
+        "إفعال" => Some("إِيعَال"),                               // إيعاد
+        "مفعال" => Some("مِيعَال"),                               // ميعاد، ميزان
+        "فواعل" if racine.lettres()[0] == 'و' => Some("أَوَاعِل"), // أواعد
+        _ => None,
+    }
+}
+
+// لفيف مفروق (و ق ي) : assimilation du 1er radical + traitement du 3ème
+// لفيف مقرون (ط و ي) : le 2ème radical reste sain, seul le 3ème est traité
+fn variante_lafif(racine: &Racine, scheme_id: &str) -> Option<&'static str> {
+    if est_faible(racine.lettres()[0]) {
+        match scheme_id {
+            "افتعل" => return Some("اتَّعَى"),  // اتّقى
+            "مفتعل" => return Some("مُتَّعٍ"),   // متّقٍ
+            "إفعال" => return Some("إِيعَاء"), // إيفاء
+            _ => {}
+        }
+    }
+    variante_defectueuse(racine, scheme_id)
+}
+
+// مضعف : les deux radicaux identiques fusionnent avec une shadda
+fn variante_geminee(scheme_id: &str) -> Option<&'static str> {
+    match scheme_id {
+        "فعل" => Some("فَعَّ"),       // مدّ
+        "فاعل" => Some("فَاعّ"),     // مادّ
+        "فاعل_III" => Some("فَاعَّ"), // مادّ (حادّ)
+        "أفعل" => Some("أَفَعَّ"),     // أمدّ
+        "افتعل" => Some("افْتَعَّ"),   // امتدّ
+        "مفتعل" => Some("مُفْتَعّ"),   // ممتدّ
+        "استفعل" => Some("اسْتَفَعَّ"), // استمدّ
+        "مستفعل" => Some("مُسْتَفِعّ"), // مستمدّ
+        "فعلي" => Some("فِعِّيّ"),     // مدّيّ، حدّيّ
+        "فاعلة" => Some("فَاعَّة"),   // مادّة، خاصّة
+        "مفعل" => Some("مَفَعّ"),     // مقرّ، ممرّ
+        "مفعلة" => Some("مَفَعَّة"),   // مودّة، محبّة
+        "فعلاء" => Some("أَفِعَّاء"),   // أشدّاء، أعزّاء
+        "فواعل" => Some("فَوَاعّ"),   // موادّ، خواصّ
+        "مفاعل" => Some("مَفَاعّ"),   // مقارّ
+        _ => None,
+    }
+}
+
+// Variante imposée par la classe de la racine pour ce schème
+// Retourne None quand le gabarit régulier du schème s'applique tel quel
+pub fn variante_pour(racine: &Racine, scheme_id: &str) -> Option<&'static str> {
+    match classifier(racine) {
+        ClasseRacine::Creuse => variante_creuse(racine, scheme_id),
+        ClasseRacine::Defectueuse => variante_defectueuse(racine, scheme_id),
+        ClasseRacine::Assimilee => variante_assimilee(racine, scheme_id),
+        ClasseRacine::Lafif => variante_lafif(racine, scheme_id),
+        ClasseRacine::Geminee => variante_geminee(scheme_id),
+        ClasseRacine::Hamzee | ClasseRacine::Saine => None,
+    }
+}
+
+// Toutes les variantes qu'un schème peut prendre selon la classe de la racine
+// (utilisé par l'analyse inverse pour reconnaître قائل comme un فاعل)
+pub fn variantes_possibles(scheme_id: &str) -> Vec<&'static str> {
+    // Une racine représentative pour chaque branche des tables de variantes
+    let representants = [
+        Racine::trilitere('ق', 'و', 'ل'),
+        Racine::trilitere('ب', 'ي', 'ع'),
+        Racine::trilitere('ر', 'م', 'ي'),
+        Racine::trilitere('د', 'ع', 'و'),
+        Racine::trilitere('و', 'ع', 'د'),
+        Racine::trilitere('و', 'ق', 'ي'),
+        Racine::trilitere('م', 'د', 'د'),
+    ];
+
+    let mut variantes = Vec::new();
+    for r in &representants {
+        if let Some(v) = variante_pour(r, scheme_id)
+            && !variantes.contains(&v)
+        {
+            variantes.push(v);
+        }
+    }
+    variantes
+}
+
+// ========================================================
+// LETTRES ET VOYELLES
+// ========================================================
+// Les ajustements ci-dessous regardent les lettres voisines : les voyelles
+// (fatha, kasra, sukun...) sont donc rattachées à la lettre qui les porte
+// pour ne pas s'intercaler entre deux lettres. La shadda reste une case à
+// part, comme dans le gabarit.
+
+// Une lettre du mot généré, avec son drapeau "vient de la racine" et ses voyelles
+#[derive(Clone)]
+struct Lettre {
+    c: char,
+    radical: bool,
+    voyelles: Vec<char>,
+}
+
+// Rattacher chaque diacritique à la lettre qui le précède
+fn regrouper(lettres: &[(char, bool)]) -> Vec<Lettre> {
+    let mut resultat: Vec<Lettre> = Vec::new();
+    for &(c, radical) in lettres {
+        if est_diacritique(c)
+            && let Some(derniere) = resultat.last_mut()
+        {
+            derniere.voyelles.push(c);
+            continue;
+        }
+        resultat.push(Lettre {
+            c,
+            radical,
+            voyelles: Vec::new(),
+        });
+    }
+    resultat
+}
+
+// ========================================================
+// ASSIMILATION DU ت DE LA FORME VIII
+// ========================================================
+// Le ت infixé de افتعل s'assimile au 1er radical :
+//   د ذ ز     → د  (ادّعى، ادّكر، ازدحم)
+//   ص ض ط ظ   → ط  (اصطبر، اضطرب، اطّلع، اظّلم)
+//   ت ث       → gémination (اتّبع، اثّأر)
+
+// Lettres que le ت infixé peut devenir après assimilation
+pub const FORMES_TA_FORME_VIII: [char; 4] = ['ت', 'ط', 'د', 'ّ'];
+
+// Appliquer l'assimilation sur le mot généré
+fn assimiler_ta_forme_viii(lettres: &mut [Lettre], scheme_id: &str) {
+    if scheme_id != "افتعل" && scheme_id != "مفتعل" {
+        return;
+    }
+
+    // Le ت infixé est la première lettre non radicale qui suit le 1er radical
+    let Some(i) = (1..lettres.len()).find(|&i| lettres[i - 1].radical && !lettres[i].radical)
+    else {
+        return;
+    };
+    if lettres[i].c != 'ت' {
+        return; // déjà assimilé par une variante (اتّعد)
+    }
+
+    match lettres[i - 1].c {
+        'د' | 'ت' | 'ث' | 'ط' => lettres[i].c = SHADDA, // même lettre : gémination
+        'ذ' => {
+            lettres[i - 1].c = 'د';
+            lettres[i].c = SHADDA;
+        }
+        'ز' => lettres[i].c = 'د',
+        'ص' | 'ض' | 'ظ' => lettres[i].c = 'ط',
+        _ => {}
+    }
+
+    // Une lettre redoublée ne garde pas son sukun : ادْ + تَ → ادَّ
+    if lettres[i].c == SHADDA {
+        lettres[i - 1].voyelles.retain(|v| *v != SUKUN);
+    }
+}
+
+// ========================================================
+// ORTHOGRAPHE DE LA HAMZA RADICALE
+// ========================================================
+
+// Schèmes dont le 2ème radical porte une kasra : une hamza finale y est
+// écrite sur ي (قارئ، مقرئ، مستقرئ) au lieu de ا (قرأ، أقرأ)
+const SCHEMES_KASRA_AIN: [&str; 5] = ["فاعل", "مفعّل", "مفتعل", "مستفعل", "مفعلل"];
+
+// Schèmes au préfixe مُ : une hamza juste après le م est écrite ؤ (مؤمّن، مؤتمن)
+const SCHEMES_DAMMA_MIM: [&str; 3] = ["مفعّل", "مفاعلة", "مفتعل"];
+
+// Voyelle brève portée par une lettre (le tanwin compte comme sa voyelle)
+fn voyelle_de(lettre: &Lettre) -> Option<char> {
+    lettre.voyelles.iter().find_map(|v| match *v {
+        FATHA | '\u{064B}' => Some(FATHA),
+        DAMMA | '\u{064C}' => Some(DAMMA),
+        KASRA | '\u{064D}' => Some(KASRA),
+        SUKUN => Some(SUKUN),
+        _ => None,
+    })
+}
+
+// Support d'une hamza non initiale d'après les voyelles qui l'entourent
+// (la plus forte l'emporte : kasra → ئ, damma → ؤ, fatha → أ)
+// Retourne None quand ni la hamza ni la lettre précédente ne sont vocalisées :
+// les règles par lettres voisines s'appliquent alors
+fn support_par_voyelles(lettres: &[Lettre], i: usize) -> Option<char> {
+    // Une hamza redoublée porte sa voyelle sur la shadda qui la suit
+    let propre = voyelle_de(&lettres[i]).or_else(|| {
+        lettres
+            .get(i + 1)
+            .filter(|l| l.c == SHADDA)
+            .and_then(voyelle_de)
+    });
+    let prec = &lettres[i - 1];
+    let voyelle_prec = voyelle_de(prec);
+    // Le ا initial est une hamzat wasl, pas une lettre de prolongation (ائتمن) ;
+    // vocalisée, elle compte comme une voyelle (اُؤْمُرْ، اِئْذَنْ)
+    let wasl_nue = i == 1 && prec.c == 'ا' && voyelle_prec.is_none();
+    if (propre.is_none() && voyelle_prec.is_none()) || wasl_nue {
+        return None;
+    }
+
+    let fin_de_mot = lettres[i + 1..]
+        .iter()
+        .all(|l| l.c == SHADDA || l.c == '\0');
+    let siege = |v: Option<char>| match v {
+        Some(KASRA) => 'ئ',
+        Some(DAMMA) => 'ؤ',
+        _ => 'أ',
+    };
+
+    // Après une lettre de prolongation (sans voyelle propre)
+    if voyelle_prec.is_none() && matches!(prec.c, 'ا' | 'و' | 'ي') {
+        return Some(match (prec.c, propre) {
+            (_, _) if fin_de_mot => 'ء',          // جاء، مقروء، راءٍ
+            ('ا', Some(KASRA)) | ('ي', _) => 'ئ', // سائل، خطيئة
+            ('ا', Some(DAMMA)) => 'ؤ',            // تفاؤل
+            _ => 'ء',                             // تساءل، مروءة
+        });
+    }
+
+    // Après une consonne, le kasratan remplace un ي élidé : la hamza n'est pas
+    // finale (مرتئٍ)
+    let finale = fin_de_mot && !lettres[i].voyelles.contains(&'\u{064D}');
+    Some(if finale {
+        // En fin de mot : seule compte la voyelle précédente (قرأ، قرئ، جرؤ)
+        match voyelle_prec {
+            Some(SUKUN) | None => 'ء',
+            v => siege(v),
+        }
+    } else {
+        // Au milieu du mot : la plus forte des deux voyelles (سئل، يقرؤون، يسأل)
+        let force = |v: Option<char>| match v {
+            Some(KASRA) => 3,
+            Some(DAMMA) => 2,
+            Some(FATHA) => 1,
+            _ => 0,
+        };
+        if force(propre) >= force(voyelle_prec) {
+            siege(propre)
+        } else {
+            siege(voyelle_prec)
+        }
+    })
+}
+
+// Corriger le support de chaque hamza radicale selon son contexte
+// Un آ ou un ي de prolongation ne porte pas de voyelle (آكِل، آمَنَ، إِيمَان)
+fn orthographier_hamzas(lettres: &[Lettre], scheme_id: &str) -> Vec<Lettre> {
+    let mut resultat = lettres.to_vec();
+    let n = resultat.len();
+
+    for i in 0..n {
+        let Lettre { c, radical, .. } = lettres[i];
+        if !radical || !est_hamza(c) {
+            continue;
+        }
+
+        let prec = if i > 0 { Some(resultat[i - 1].c) } else { None };
+        let suiv = if i + 1 < n {
+            Some(resultat[i + 1].c)
+        } else {
+            None
+        };
+
+        let par_voyelles = if i > 0 {
+            support_par_voyelles(&resultat, i)
+        } else {
+            None
+        };
+
+        resultat[i].c = match (prec, suiv) {
+            // En tête de mot devant ا : أا → آ (آكل)
+            (None, Some('ا')) => {
+                resultat[i + 1].c = '\0';
+                resultat[i].voyelles.clear();
+                'آ'
+            }
+            // En tête de mot : أ (أكل)
+            (None, _) => 'أ',
+            // Deux hamzas de suite : أَأْ → آ (آمن), أُأْ → أو (أُومِنُ), إأ → إي (إيمان)
+            (Some('أ'), _) if i == 1 && voyelle_de(&resultat[0]) == Some(DAMMA) => {
+                resultat[i].voyelles.clear();
+                'و'
+            }
+            (Some('أ'), _) | (Some('آ'), _) if i == 1 => {
+                resultat[0].c = 'آ';
+                resultat[0].voyelles.clear();
+                '\0'
+            }
+            (Some('إ'), _) if i == 1 => {
+                resultat[i].voyelles.clear();
+                'ي'
+            }
+            // Après le مُ des participes dérivés : ؤ (مؤمّن، مؤاكلة، مؤتمن)
+            (Some('م'), _) if i == 1 && SCHEMES_DAMMA_MIM.contains(&scheme_id) => 'ؤ',
+            // Devant ا après une consonne : أا → آ (إسآل، قرآ), mais pas quand les
+            // voyelles demandent un autre support (قرئا، يجيئان)
+            (Some(p), Some('ا')) if p != 'ا' && par_voyelles.is_none_or(|s| s == 'أ') => {
+                resultat[i + 1].c = '\0';
+                resultat[i].voyelles.clear();
+                'آ'
+            }
+            // Mot vocalisé : le support se déduit des voyelles (يقرأ، يقرئ، قرؤوا)
+            (Some(_), _) if par_voyelles.is_some() => par_voyelles.unwrap_or(c),
+            // Devant le و de مفعول : ؤ (مسؤول)
+            (_, Some('و')) => 'ؤ',
+            // Après un ا long, au milieu du mot : ئ (سائل)
+            (Some('ا'), Some(_)) => 'ئ',
+            // En fin de mot après une voyelle longue : ء (مقروء، بريء، إنشاء)
+            (Some('ا'), None) | (Some('و'), None) | (Some('ي'), None) => 'ء',
+            // En fin de mot après une consonne : dépend de la voyelle du 2ème radical
+            (Some(_), None) => {
+                if SCHEMES_KASRA_AIN.contains(&scheme_id) {
+                    'ئ'
+                } else {
+                    'أ'
+                }
+            }
+            // Devant ي : ئ ; ailleurs au milieu du mot : أ (مأكول، مسألة)
+            (Some(_), Some('ي')) => 'ئ',
+            (Some(_), Some(_)) => 'أ',
+        };
+    }
+
+    // '\0' marque une lettre absorbée par un آ (ses voyelles disparaissent avec elle)
+    resultat.into_iter().filter(|l| l.c != '\0').collect()
+}
+
+// Appliquer tous les ajustements orthographiques au mot généré
+// `lettres` : le mot, avec pour chaque lettre un booléen "vient de la racine"
+// (les diacritiques du gabarit y figurent avec le drapeau false)
+pub fn ajuster_orthographe(lettres: &[(char, bool)], scheme_id: &str) -> String {
+    let mut lettres = regrouper(lettres);
+    assimiler_ta_forme_viii(&mut lettres, scheme_id);
+    orthographier_hamzas(&lettres, scheme_id)
+        .into_iter()
+        .flat_map(|l| std::iter::once(l.c).chain(l.voyelles))
+        .collect()
+}
@@ -0,0 +1,349 @@
+// ============================================================================
+// PROPRIÉTÉS MORPHOLOGIQUES D'UN SCHÈME
+// ============================================================================
+// La description d'un schème ("participe actif forme II") est un texte libre :
+// elle ne permet ni de filtrer ni de distinguer فاعل (participe) de فاعل_III
+// (verbe). Chaque schème porte donc des propriétés typées, toutes facultatives
+// (un schème saisi par l'utilisateur n'en a aucune) :
+//   forme   → forme verbale, 1 à 15 (I à XV) pour les trilitères, 1 à 4
+//             (QI à QIV) pour les quadrilitères
+//   quadrilitere → la forme est celle d'un verbe quadrilitère : QII (تفعلل)
+//             n'est pas la forme II (فعّل), un filtre sur l'une exclut l'autre
+//   nature  → nom, adjectif, verbe, masdar, participe
+//   voix    → actif / passif (verbes et participes)
+//   aspect  → accompli / inaccompli / impératif (verbes)
+//   nombre, genre → pour les noms, adjectifs et participes
+//
+// `Criteres` sélectionne les schèmes d'une table (voir `SchemeTable::filtrer`) :
+// un critère absent accepte tout, un critère donné exige la même valeur.
+
+use serde::{Deserialize, Serialize};
+
+use crate::hashing::{Categorie, Scheme};
+use crate::normalisation::Normalisation;
+
+// Nature (partie du discours) du mot produit par le schème
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Nature {
+    Nom,
+    Adjectif,
+    Verbe,
+    Masdar,
+    Participe,
+}
+
+impl Nature {
+    pub const TOUTES: [Nature; 5] = [
+        Nature::Nom,
+        Nature::Adjectif,
+        Nature::Verbe,
+        Nature::Masdar,
+        Nature::Participe,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Nature::Nom => "nom",
+            Nature::Adjectif => "adjectif",
+            Nature::Verbe => "verbe",
+            Nature::Masdar => "masdar",
+            Nature::Participe => "participe",
+        }
+    }
+
+    pub fn depuis_texte(texte: &str) -> Option<Nature> {
+        Nature::TOUTES.into_iter().find(|n| n.code() == texte)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Voix {
+    Actif,
+    Passif,
+}
+
+impl Voix {
+    pub const TOUTES: [Voix; 2] = [Voix::Actif, Voix::Passif];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Voix::Actif => "actif",
+            Voix::Passif => "passif",
+        }
+    }
+
+    pub fn depuis_texte(texte: &str) -> Option<Voix> {
+        Voix::TOUTES.into_iter().find(|v| v.code() == texte)
+    }
+}
+
+// Aspect (temps) d'un schème verbal
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aspect {
+    Accompli,
+    Inaccompli,
+    Imperatif,
+}
+
+impl Aspect {
+    pub const TOUTES: [Aspect; 3] = [Aspect::Accompli, Aspect::Inaccompli, Aspect::Imperatif];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Aspect::Accompli => "accompli",
+            Aspect::Inaccompli => "inaccompli",
+            Aspect::Imperatif => "imperatif",
+        }
+    }
+
+    pub fn depuis_texte(texte: &str) -> Option<Aspect> {
+        Aspect::TOUTES.into_iter().find(|a| a.code() == texte)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Nombre {
+    Singulier,
+    Duel,
+    Pluriel,
+}
+
+impl Nombre {
+    pub const TOUTES: [Nombre; 3] = [Nombre::Singulier, Nombre::Duel, Nombre::Pluriel];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Nombre::Singulier => "singulier",
+            Nombre::Duel => "duel",
+            Nombre::Pluriel => "pluriel",
+        }
+    }
+
+    pub fn depuis_texte(texte: &str) -> Option<Nombre> {
+        Nombre::TOUTES.into_iter().find(|n| n.code() == texte)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Genre {
+    Masculin,
+    Feminin,
+}
+
+impl Genre {
+    pub const TOUTES: [Genre; 2] = [Genre::Masculin, Genre::Feminin];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Genre::Masculin => "masculin",
+            Genre::Feminin => "feminin",
+        }
+    }
+
+    pub fn depuis_texte(texte: &str) -> Option<Genre> {
+        Genre::TOUTES.into_iter().find(|g| g.code() == texte)
+    }
+}
+
+// ============================================================================
+// FORMES VERBALES
+// ============================================================================
+
+// Numéro le plus élevé d'une forme verbale trilitère (XV)
+pub const FORME_MAX: u8 = 15;
+
+// Numéro le plus élevé d'une forme verbale quadrilitère (QIV)
+pub const FORME_MAX_QUADRILITERE: u8 = 4;
+
+const ROMAINS: [&str; FORME_MAX as usize] = [
+    "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII", "XIII", "XIV", "XV",
+];
+
+// Numéro de forme en chiffres romains (4 → "IV"), "?" hors de 1..=15
+pub fn numero_romain(forme: u8) -> &'static str {
+    match forme {
+        1..=FORME_MAX => ROMAINS[forme as usize - 1],
+        _ => "?",
+    }
+}
+
+// Forme verbale écrite en chiffres romains (sans casse) ou arabes : "iv", "4"
+pub fn forme_verbale(texte: &str) -> Option<u8> {
+    let texte = texte.trim();
+    if let Ok(n) = texte.parse::<u8>() {
+        return (1..=FORME_MAX).contains(&n).then_some(n);
+    }
+    ROMAINS
+        .iter()
+        .position(|r| r.eq_ignore_ascii_case(texte))
+        .map(|i| i as u8 + 1)
+}
+
+// Forme verbale trilitère ("iv", "4") ou quadrilitère ("QII", "q2") ;
+// le booléen indique une forme quadrilitère
+pub fn forme_et_systeme(texte: &str) -> Option<(u8, bool)> {
+    let texte = texte.trim();
+    match texte.strip_prefix(['Q', 'q']) {
+        Some(reste) => forme_verbale(reste)
+            .filter(|f| *f <= FORME_MAX_QUADRILITERE)
+            .map(|f| (f, true)),
+        None => forme_verbale(texte).map(|f| (f, false)),
+    }
+}
+
+// ============================================================================
+// PROPRIÉTÉS
+// ============================================================================
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Proprietes {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forme: Option<u8>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub quadrilitere: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nature: Option<Nature>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voix: Option<Voix>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect: Option<Aspect>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nombre: Option<Nombre>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genre: Option<Genre>,
+}
+
+impl Proprietes {
+    // Verbe à l'accompli actif, 3ème pers. masc. sing. (كَتَبَ)
+    pub fn verbe(forme: u8) -> Proprietes {
+        Proprietes {
+            forme: Some(forme),
+            nature: Some(Nature::Verbe),
+            voix: Some(Voix::Actif),
+            aspect: Some(Aspect::Accompli),
+            nombre: Some(Nombre::Singulier),
+            genre: Some(Genre::Masculin),
+            ..Proprietes::default()
+        }
+    }
+
+    // Participe masc. sing. (كَاتِب, مَكْتُوب)
+    pub fn participe(forme: u8, voix: Voix) -> Proprietes {
+        Proprietes {
+            forme: Some(forme),
+            nature: Some(Nature::Participe),
+            voix: Some(voix),
+            nombre: Some(Nombre::Singulier),
+            genre: Some(Genre::Masculin),
+            ..Proprietes::default()
+        }
+    }
+
+    pub fn masdar(forme: u8) -> Proprietes {
+        Proprietes {
+            forme: Some(forme),
+            nature: Some(Nature::Masdar),
+            ..Proprietes::default()
+        }
+    }
+
+    pub fn nom(nombre: Nombre, genre: Genre) -> Proprietes {
+        Proprietes {
+            nature: Some(Nature::Nom),
+            nombre: Some(nombre),
+            genre: Some(genre),
+            ..Proprietes::default()
+        }
+    }
+
+    pub fn adjectif(nombre: Nombre, genre: Genre) -> Proprietes {
+        Proprietes {
+            nature: Some(Nature::Adjectif),
+            ..Proprietes::nom(nombre, genre)
+        }
+    }
+
+    // Pluriel brisé : le genre dépend du singulier, il n'est pas fixé
+    pub fn pluriel() -> Proprietes {
+        Proprietes {
+            nature: Some(Nature::Nom),
+            nombre: Some(Nombre::Pluriel),
+            ..Proprietes::default()
+        }
+    }
+
+    // Les mêmes propriétés, pour un schème de verbe quadrilitère (QI, QII)
+    pub fn quadrilitere(self) -> Proprietes {
+        Proprietes {
+            quadrilitere: true,
+            ..self
+        }
+    }
+
+    // Forme verbale écrite en chiffres romains : "IV", "QII"
+    pub fn nom_forme(&self) -> Option<String> {
+        let prefixe = if self.quadrilitere { "Q" } else { "" };
+        self.forme
+            .map(|f| format!("{}{}", prefixe, numero_romain(f)))
+    }
+
+    // Ces propriétés satisfont-elles toutes celles qui sont données dans `motif` ?
+    // Une forme donnée n'accepte que le même système (trilitère ou quadrilitère) ;
+    // un motif quadrilitère sans forme n'accepte que les quadrilitères
+    pub fn inclut(&self, motif: &Proprietes) -> bool {
+        fn accepte<T: PartialEq>(valeur: Option<T>, attendu: Option<T>) -> bool {
+            attendu.is_none() || valeur == attendu
+        }
+        let meme_systeme = if motif.forme.is_some() {
+            self.quadrilitere == motif.quadrilitere
+        } else {
+            self.quadrilitere || !motif.quadrilitere
+        };
+        accepte(self.forme, motif.forme)
+            && meme_systeme
+            && accepte(self.nature, motif.nature)
+            && accepte(self.voix, motif.voix)
+            && accepte(self.aspect, motif.aspect)
+            && accepte(self.nombre, motif.nombre)
+            && accepte(self.genre, motif.genre)
+    }
+}
+
+// ============================================================================
+// CRITÈRES DE RECHERCHE
+// ============================================================================
+
+// Critères de `SchemeTable::filtrer` ; la valeur par défaut accepte tout
+// Côté JavaScript : { "proprietes": { "forme": 4 }, "categorie": "verbe", "modele": "فاعل" }
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Criteres {
+    #[serde(default)]
+    pub proprietes: Proprietes,
+    #[serde(default)]
+    pub categorie: Option<Categorie>,
+    // Nombre de radicaux (3 ou 4)
+    #[serde(default)]
+    pub radicaux: Option<usize>,
+    // Nom (forme) du schème, quel que soit son identifiant : "فاعل" accepte
+    // فاعل et فاعل_III ; comparé comme une clé de schème (افعال = أفعال)
+    #[serde(default)]
+    pub modele: Option<String>,
+}
+
+impl Criteres {
+    pub fn accepte(&self, scheme: &Scheme) -> bool {
+        let cle = |texte: &str| Normalisation::CLE_SCHEME.appliquer(texte);
+        scheme.proprietes.inclut(&self.proprietes)
+            && self.categorie.is_none_or(|c| c == scheme.categorie)
+            && self.radicaux.is_none_or(|n| n == scheme.nombre_radicaux())
+            && self
+                .modele
+                .as_deref()
+                .is_none_or(|m| cle(m) == cle(&scheme.nom))
+    }
+}
@@ -0,0 +1,184 @@
+// ============================================================================
+// RACINE ARABE (trilitère ou quadrilitère)
+// ============================================================================
+// Une racine contient 3 radicaux (ك ت ب) ou 4 radicaux (ت ر ج م).
+// Les lettres sont stockées dans un tableau fixe de 4 cases pour que le type
+// reste `Copy` ; `longueur` indique combien de cases sont utilisées.
+//
+// Une `Racine` est toujours valide : les constructeurs publics refusent tout
+// radical qui n'est pas une consonne arabe (lettre latine, chiffre, espace,
+// diacritique...) avec l'erreur LettreNonArabe (caractère et position).
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::erreur::MorphoError;
+use crate::normalisation::Normalisation;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(into = "Vec<char>", try_from = "Vec<char>")]
+pub struct Racine {
+    lettres: [char; 4],
+    longueur: usize,
+}
+
+// Consonnes admises comme radicaux : les 28 lettres, toutes les formes de la
+// hamza, et ى (ر م ى). Le ا (et ٱ) est admis comme hamza saisie sans siège
+// (ا ك ل, voir Racine::cle) ; ة, les voyelles et le tatweel ne sont jamais
+// des radicaux.
+pub const LETTRES_RADICALES: [char; 36] = [
+    'ء', 'أ', 'إ', 'آ', 'ؤ', 'ئ', 'ا', 'ب', 'ت', 'ث', 'ج', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش',
+    'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ك', 'ل', 'م', 'ن', 'ه', 'و', 'ي', 'ى', 'ٱ',
+];
+
+impl Racine {
+    // Racine trilitère (فعل), sans vérification : lettres connues de la crate
+    pub(crate) fn trilitere(c1: char, c2: char, c3: char) -> Self {
+        Racine {
+            lettres: [c1, c2, c3, '\0'],
+            longueur: 3,
+        }
+    }
+
+    // Racine quadrilitère (فعلل), sans vérification
+    pub(crate) fn quadrilitere(c1: char, c2: char, c3: char, c4: char) -> Self {
+        Racine {
+            lettres: [c1, c2, c3, c4],
+            longueur: 4,
+        }
+    }
+
+    // Construire une racine à partir des radicaux reçus de l'API (c4 optionnel)
+    pub fn depuis_radicaux(
+        c1: char,
+        c2: char,
+        c3: char,
+        c4: Option<char>,
+    ) -> Result<Self, MorphoError> {
+        match c4 {
+            Some(c4) => Racine::depuis_lettres(&[c1, c2, c3, c4]),
+            None => Racine::depuis_lettres(&[c1, c2, c3]),
+        }
+    }
+
+    // Construire une racine à partir d'une liste de lettres
+    // Erreur LongueurRacine si on n'a pas exactement 3 ou 4 lettres,
+    // LettreNonArabe si une lettre n'est pas dans LETTRES_RADICALES
+    pub fn depuis_lettres(lettres: &[char]) -> Result<Self, MorphoError> {
+        Racine::depuis_lettres_parmi(lettres, &LETTRES_RADICALES)
+    }
+
+    // Comme depuis_lettres, avec un autre jeu de radicaux admis
+    // (ex: LETTRES_RADICALES complété de پ چ ڤ گ pour des emprunts)
+    pub fn depuis_lettres_parmi(lettres: &[char], admises: &[char]) -> Result<Self, MorphoError> {
+        if let Some(i) = lettres.iter().position(|c| !admises.contains(c)) {
+            return Err(MorphoError::LettreNonArabe {
+                lettre: lettres[i],
+                position: i + 1,
+            });
+        }
+        match lettres {
+            [c1, c2, c3] => Ok(Racine::trilitere(*c1, *c2, *c3)),
+            [c1, c2, c3, c4] => Ok(Racine::quadrilitere(*c1, *c2, *c3, *c4)),
+            _ => Err(MorphoError::LongueurRacine(lettres.len())),
+        }
+    }
+
+    // Construire une racine depuis du texte : "ك ت ب", "كتب" ou "ت ر ج م"
+    // Le texte est nettoyé (tatweel, formes de présentation) et les voyelles
+    // éventuelles sont retirées ; les lettres elles-mêmes sont gardées telles
+    // quelles (أ reste أ) pour l'affichage
+    pub fn depuis_texte(texte: &str) -> Result<Self, MorphoError> {
+        let regles = Normalisation {
+            voyelles: true,
+            shadda: true,
+            ..Normalisation::SAISIE
+        };
+        let chars: Vec<char> = regles
+            .appliquer(texte)
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        Racine::depuis_lettres(&chars)
+    }
+
+    // Les radicaux effectivement utilisés (3 ou 4)
+    pub fn lettres(&self) -> &[char] {
+        &self.lettres[..self.longueur]
+    }
+
+    // Nombre de radicaux (3 ou 4)
+    pub fn longueur(&self) -> usize {
+        self.longueur
+    }
+
+    pub fn est_quadrilitere(&self) -> bool {
+        self.longueur == 4
+    }
+
+    // Radical n°i (0 = ف, 1 = ع, 2 = ل, 3 = 2ème ل)
+    pub fn radical(&self, i: usize) -> Option<char> {
+        self.lettres().get(i).copied()
+    }
+
+    // Clé de comparaison : la racine repliée (أ ك ل → ء ك ل, ر م ى → ر م ي)
+    // Deux racines de même clé sont la même racine pour l'arbre
+    // Un ا n'est jamais un radical : c'est une hamza saisie sans son siège (ا ك ل)
+    pub fn cle(&self) -> Racine {
+        let mut cle = *self;
+        for c in &mut cle.lettres[..cle.longueur] {
+            *c = Racine::cle_lettre(*c);
+        }
+        cle
+    }
+
+    // Un radical replié comme dans `cle` (أ → ء, ى → ي) ; ا et ٱ (alif
+    // wasla), jamais radicaux, sont des hamzas saisies sans leur siège
+    pub fn cle_lettre(c: char) -> char {
+        match Normalisation::RACINE.lettre(c) {
+            'ا' | 'ٱ' => 'ء',
+            l => l,
+        }
+    }
+}
+
+impl fmt::Display for Racine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.lettres() {
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<[char; 3]> for Racine {
+    type Error = MorphoError;
+
+    fn try_from(r: [char; 3]) -> Result<Self, Self::Error> {
+        Racine::depuis_lettres(&r)
+    }
+}
+
+impl TryFrom<[char; 4]> for Racine {
+    type Error = MorphoError;
+
+    fn try_from(r: [char; 4]) -> Result<Self, Self::Error> {
+        Racine::depuis_lettres(&r)
+    }
+}
+
+// Sérialisation : une racine est une simple liste de lettres ["ك","ت","ب"]
+// (compatible avec les anciens exports JSON qui stockaient un [char; 3])
+impl From<Racine> for Vec<char> {
+    fn from(r: Racine) -> Self {
+        r.lettres().to_vec()
+    }
+}
+
+impl TryFrom<Vec<char>> for Racine {
+    type Error = MorphoError;
+
+    fn try_from(lettres: Vec<char>) -> Result<Self, Self::Error> {
+        Racine::depuis_lettres(&lettres)
+    }
+}
@@ -0,0 +1,388 @@
+// ============================================================================
+// REQUÊTES ORDONNÉES SUR L'ARBRE DES RACINES
+// ============================================================================
+// L'arbre AVL est trié par clé de racine (voir morphologic_cmp) : l'ordre est
+// celui du dictionnaire, lettre par lettre. Trois requêtes en profitent :
+//
+//   intervalle : ك .. م  → racines dont la clé est entre ك et م, bornes
+//                          comprises (م ن ع est dans l'intervalle)
+//   préfixe    : ك ت     → كتب، كتف، كتم...
+//   motif      : ? ت ب   → كتب، رتب، عتب... (? = n'importe quel radical ; le
+//                          motif fixe aussi le nombre de radicaux)
+//
+// Élagage : un sous-arbre n'est visité que s'il peut contenir une racine de
+// l'intervalle (le sous-arbre gauche d'un nœud avant la borne basse, le droit
+// d'un nœud après la borne haute sont ignorés). Pour un motif, les lettres
+// fixées avant le premier ? forment un préfixe ; à chaque ?, on saute d'une
+// lettre présente à la suivante (recherche du successeur en O(log n)) au lieu
+// de parcourir toutes les racines qui commencent par le préfixe.
+//
+// Les résultats sont dans l'ordre de l'arbre et paginés : `total` compte toutes
+// les racines trouvées, `racines` ne garde que la page demandée.
+// Bornes, préfixes et motifs sont repliés comme les racines (أ = ء, ى = ي).
+
+use serde::{Deserialize, Serialize};
+
+use crate::arbre::{RacineNode, Tree};
+use crate::erreur::MorphoError;
+use crate::normalisation::Normalisation;
+use crate::racine::{LETTRES_RADICALES, Racine};
+
+// Taille de page par défaut
+pub const LIMITE_PAGE: usize = 50;
+
+// Jokers d'un motif (؟ : point d'interrogation arabe)
+const JOKERS: [char; 2] = ['?', '؟'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pagination {
+    // Nombre de résultats sautés
+    pub decalage: usize,
+    // Nombre maximal de résultats rendus
+    pub limite: usize,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Pagination {
+            decalage: 0,
+            limite: LIMITE_PAGE,
+        }
+    }
+}
+
+// Une page de résultats : les nœuds de l'arbre (racine, dérivés, fréquence)
+pub struct PageRacines<'a> {
+    pub total: usize,
+    pub pagination: Pagination,
+    pub racines: Vec<&'a RacineNode>,
+}
+
+impl<'a> PageRacines<'a> {
+    fn new(pagination: Pagination) -> Self {
+        PageRacines {
+            total: 0,
+            pagination,
+            racines: Vec::new(),
+        }
+    }
+
+    // Compter un résultat, et le garder s'il tombe dans la page
+    fn ajouter(&mut self, noeud: &'a RacineNode) {
+        if self.total >= self.pagination.decalage && self.racines.len() < self.pagination.limite {
+            self.racines.push(noeud);
+        }
+        self.total += 1;
+    }
+}
+
+impl Tree {
+    // Racines entre deux bornes (comprises) ; None : pas de borne de ce côté
+    pub fn racines_entre(
+        &self,
+        debut: Option<&str>,
+        fin: Option<&str>,
+        pagination: Pagination,
+    ) -> Result<PageRacines<'_>, MorphoError> {
+        let debut = debut.map(lettres_prefixe).transpose()?;
+        let fin = fin.map(lettres_prefixe).transpose()?;
+        let mut page = PageRacines::new(pagination);
+        intervalle(&self.racine, debut.as_deref(), fin.as_deref(), &mut |n| {
+            page.ajouter(n)
+        });
+        Ok(page)
+    }
+
+    // Racines qui commencent par ces lettres (1 à 4)
+    pub fn racines_prefixe(
+        &self,
+        prefixe: &str,
+        pagination: Pagination,
+    ) -> Result<PageRacines<'_>, MorphoError> {
+        let prefixe = lettres_prefixe(prefixe)?;
+        let mut page = PageRacines::new(pagination);
+        intervalle(&self.racine, Some(&prefixe), Some(&prefixe), &mut |n| {
+            page.ajouter(n)
+        });
+        Ok(page)
+    }
+
+    // Racines qui correspondent à un motif de 3 ou 4 radicaux ("? ت ب")
+    pub fn racines_motif(
+        &self,
+        motif: &str,
+        pagination: Pagination,
+    ) -> Result<PageRacines<'_>, MorphoError> {
+        let motif = lettres_motif(motif)?;
+        let mut page = PageRacines::new(pagination);
+        self.chercher_motif(&mut Vec::new(), &motif, &mut |n| page.ajouter(n));
+        Ok(page)
+    }
+
+    // Compléter `prefixe` avec le reste du motif
+    fn chercher_motif<'a>(
+        &'a self,
+        prefixe: &mut Vec<char>,
+        reste: &[Option<char>],
+        visite: &mut dyn FnMut(&'a RacineNode),
+    ) {
+        match reste.split_first() {
+            // Motif complet : la racine existe ou non
+            None => {
+                if let Some(noeud) = self.noeud_cle(prefixe) {
+                    visite(noeud);
+                }
+            }
+            Some((Some(c), suite)) => {
+                prefixe.push(*c);
+                self.chercher_motif(prefixe, suite, visite);
+                prefixe.pop();
+            }
+            // Plus aucune lettre fixée : toutes les racines du préfixe, de la
+            // bonne longueur
+            Some((None, suite)) if suite.iter().all(|c| c.is_none()) => {
+                let longueur = prefixe.len() + reste.len();
+                intervalle(&self.racine, Some(prefixe), Some(prefixe), &mut |n| {
+                    if n.racine.longueur() == longueur {
+                        visite(n);
+                    }
+                });
+            }
+            // Joker suivi d'une lettre fixée : essayer chaque lettre présente
+            // à cette position, de successeur en successeur
+            Some((None, suite)) => {
+                let mut borne = prefixe.clone();
+                borne.push('\0');
+                while let Some(cle) = self.premiere_cle_depuis(&borne) {
+                    let cle = cle.lettres();
+                    if !cle.starts_with(prefixe) {
+                        break;
+                    }
+                    let lettre = cle[prefixe.len()];
+                    prefixe.push(lettre);
+                    self.chercher_motif(prefixe, suite, visite);
+                    prefixe.pop();
+
+                    // Lettre suivante : première clé après toutes celles en `lettre`
+                    borne.truncate(prefixe.len());
+                    borne.push(char::from_u32(lettre as u32 + 1).unwrap_or(char::MAX));
+                }
+            }
+        }
+    }
+
+    // Nœud dont la clé est exactement `cle`
+    fn noeud_cle(&self, cle: &[char]) -> Option<&RacineNode> {
+        let mut courant = self.racine.as_deref();
+        while let Some(noeud) = courant {
+            let cle_noeud = noeud.racine.cle();
+            courant = match cle_noeud.lettres().cmp(cle) {
+                std::cmp::Ordering::Equal => return Some(noeud),
+                std::cmp::Ordering::Greater => noeud.left.as_deref(),
+                std::cmp::Ordering::Less => noeud.right.as_deref(),
+            };
+        }
+        None
+    }
+
+    // Plus petite clé de l'arbre supérieure ou égale à `borne`
+    fn premiere_cle_depuis(&self, borne: &[char]) -> Option<Racine> {
+        let mut courant = self.racine.as_deref();
+        let mut trouvee = None;
+        while let Some(noeud) = courant {
+            let cle = noeud.racine.cle();
+            if cle.lettres() >= borne {
+                trouvee = Some(cle);
+                courant = noeud.left.as_deref();
+            } else {
+                courant = noeud.right.as_deref();
+            }
+        }
+        trouvee
+    }
+}
+
+// Parcours in-order élagué : les nœuds dont la clé est >= debut et dont le
+// début (longueur de `fin`) est <= fin
+fn intervalle<'a>(
+    noeud: &'a Option<Box<RacineNode>>,
+    debut: Option<&[char]>,
+    fin: Option<&[char]>,
+    visite: &mut dyn FnMut(&'a RacineNode),
+) {
+    let Some(n) = noeud else {
+        return;
+    };
+    let cle = n.racine.cle();
+    let cle = cle.lettres();
+    let apres_debut = debut.is_none_or(|d| cle >= d);
+    let avant_fin = fin.is_none_or(|f| &cle[..cle.len().min(f.len())] <= f);
+
+    // Le sous-arbre gauche n'a que des clés plus petites que celle du nœud
+    if debut.is_none_or(|d| cle > d) {
+        intervalle(&n.left, debut, fin, visite);
+    }
+    if apres_debut && avant_fin {
+        visite(n);
+    }
+    // Le sous-arbre droit n'a que des clés plus grandes
+    if avant_fin {
+        intervalle(&n.right, debut, fin, visite);
+    }
+}
+
+// Lettres d'une saisie, sans voyelles ni espaces ; None pour un joker
+fn lettres_saisies(texte: &str, jokers: bool) -> Result<Vec<Option<char>>, MorphoError> {
+    let regles = Normalisation {
+        voyelles: true,
+        shadda: true,
+        ..Normalisation::SAISIE
+    };
+    regles
+        .appliquer(texte)
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .enumerate()
+        .map(|(i, c)| {
+            if jokers && JOKERS.contains(&c) {
+                Ok(None)
+            } else if LETTRES_RADICALES.contains(&c) {
+                Ok(Some(Racine::cle_lettre(c)))
+            } else {
+                Err(MorphoError::LettreNonArabe {
+                    lettre: c,
+                    position: i + 1,
+                })
+            }
+        })
+        .collect()
+}
+
+// Borne ou préfixe : 1 à 4 radicaux
+fn lettres_prefixe(texte: &str) -> Result<Vec<char>, MorphoError> {
+    let lettres: Vec<char> = lettres_saisies(texte, false)?
+        .into_iter()
+        .flatten()
+        .collect();
+    if !(1..=4).contains(&lettres.len()) {
+        return Err(MorphoError::RequeteInvalide(format!(
+            "'{}' : 1 à 4 radicaux attendus ({} reçus)",
+            texte,
+            lettres.len()
+        )));
+    }
+    Ok(lettres)
+}
+
+// Motif : 3 ou 4 positions, radical ou joker
+fn lettres_motif(texte: &str) -> Result<Vec<Option<char>>, MorphoError> {
+    let motif = lettres_saisies(texte, true)?;
+    if !(3..=4).contains(&motif.len()) {
+        return Err(MorphoError::RequeteInvalide(format!(
+            "motif '{}' : 3 ou 4 positions attendues, radicaux ou ? ({} reçues)",
+            texte,
+            motif.len()
+        )));
+    }
+    Ok(motif)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RACINES: [&str; 12] = [
+        "ق ر أ",
+        "ك ت ب",
+        "ك ت ف",
+        "ك ث ر",
+        "ل ع ب",
+        "م ن ع",
+        "ن ص ر",
+        "ر ت ب",
+        "ع ت ب",
+        "ع ت ب ر",
+        "ر ت ل",
+        "ب ت ر",
+    ];
+
+    // Arbre des RACINES, complété de racines qui les répartissent dans
+    // plusieurs sous-arbres
+    fn arbre() -> Tree {
+        let mut arbre = Tree::new();
+        for texte in RACINES {
+            arbre.insert(Racine::depuis_texte(texte).unwrap());
+        }
+        for c in ['ب', 'ج', 'د', 'س', 'ش', 'ص', 'ط', 'ف', 'ه', 'و', 'ي'] {
+            arbre.insert(Racine::depuis_radicaux(c, 'ل', 'م', None).unwrap());
+        }
+        arbre
+    }
+
+    fn textes(page: &PageRacines) -> Vec<String> {
+        page.racines.iter().map(|n| n.racine.to_string()).collect()
+    }
+
+    #[test]
+    fn intervalle_bornes_comprises() {
+        let arbre = arbre();
+        let page = arbre
+            .racines_entre(Some("ك"), Some("م"), Pagination::default())
+            .unwrap();
+        assert_eq!(textes(&page), ["كتب", "كتف", "كثر", "لعب", "منع"]);
+        assert_eq!(page.total, 5);
+    }
+
+    #[test]
+    fn prefixe_sans_la_racine_voisine() {
+        let arbre = arbre();
+        let page = arbre.racines_prefixe("ك ت", Pagination::default()).unwrap();
+        assert_eq!(textes(&page), ["كتب", "كتف"]);
+    }
+
+    #[test]
+    fn motif_dans_plusieurs_sous_arbres() {
+        let arbre = arbre();
+        let page = arbre.racines_motif("? ت ب", Pagination::default()).unwrap();
+        // عتبر a 4 radicaux : exclu par la longueur du motif ; رتل par la lettre
+        assert_eq!(textes(&page), ["رتب", "عتب", "كتب"]);
+        assert_eq!(page.total, 3);
+
+        let page = arbre
+            .racines_motif("? ت ب ?", Pagination::default())
+            .unwrap();
+        assert_eq!(textes(&page), ["عتبر"]);
+    }
+
+    #[test]
+    fn pagination_sans_changer_le_total() {
+        let arbre = arbre();
+        let pagination = Pagination {
+            decalage: 1,
+            limite: 2,
+        };
+        let page = arbre
+            .racines_entre(Some("ك"), Some("م"), pagination)
+            .unwrap();
+        assert_eq!(textes(&page), ["كتف", "كثر"]);
+        assert_eq!(page.total, 5);
+
+        let pagination = Pagination {
+            decalage: 4,
+            limite: 2,
+        };
+        let page = arbre
+            .racines_entre(Some("ك"), Some("م"), pagination)
+            .unwrap();
+        assert_eq!(textes(&page), ["منع"]);
+        assert_eq!(page.total, 5);
+
+        let pagination = Pagination {
+            decalage: 5,
+            limite: 2,
+        };
+        let page = arbre.racines_motif("? ت ب", pagination).unwrap();
+        assert!(page.racines.is_empty());
+        assert_eq!(page.total, 3);
+    }
+}
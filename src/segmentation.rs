@@ -0,0 +1,289 @@
+// ============================================================================
+// SEGMENTATION DES CLITIQUES
+// ============================================================================
+// Dans un texte, un mot porte souvent des clitiques collés à sa base :
+//   والمكتوب  → و + ال + مكتوب
+//   بكاتبه    → ب + كاتب + ه
+//   وسيكتب    → و + س + يكتب
+//
+// `segmenter` propose TOUTES les découpes plausibles (préfixes / base /
+// suffixe), la découpe sans clitique en premier : c'est la validation ou
+// l'analyse de la base qui tranche (كتاب n'est pas ك + تاب pour ك ت ب).
+//
+// Proclitiques, dans cet ordre : conjonction (و، ف), puis préposition (ب، ك، ل)
+// ou particule du futur (س), puis article (ال ; ل + ال s'écrit لل).
+// Enclitique : un pronom suffixe (ه، ها، هم، كم، نا...).
+//
+// Règles d'écriture prises en compte :
+//   - pas de pronom suffixe après l'article (الكتابه n'est pas ال + كتاب + ه)
+//   - س n'est suivi que d'un inaccompli (ي، ت، ن، أ)
+//   - préposition et article précèdent un nom, س un verbe (voir `admet`)
+//   - lettre solaire : la shadda d'assimilation (الشَّمْس) est retirée de la base
+//   - devant un pronom : ة s'écrit ت (كتابته), ى s'écrit ا (رماه), et le
+//     ا final de وا tombe (كتبوه) ; les deux lectures sont proposées
+
+use serde::{Deserialize, Serialize};
+
+use crate::gabarit::{SHADDA, est_diacritique};
+use crate::normalisation::Normalisation;
+use crate::proprietes::{Aspect, Nature, Proprietes};
+
+// Rôle d'un clitique
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Conjonction,
+    Preposition,
+    Futur,
+    Article,
+    Pronom,
+}
+
+impl Role {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Role::Conjonction => "conjonction",
+            Role::Preposition => "preposition",
+            Role::Futur => "futur",
+            Role::Article => "article",
+            Role::Pronom => "pronom",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Clitique {
+    // Forme écrite du clitique dans le mot (و، ال، ه)
+    pub forme: String,
+    pub role: Role,
+}
+
+// Une découpe du mot : proclitiques, base, enclitiques
+// La base garde la vocalisation saisie (sauf la voyelle finale devant un
+// pronom, qui est une désinence)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Segmentation {
+    pub proclitiques: Vec<Clitique>,
+    pub base: String,
+    pub enclitiques: Vec<Clitique>,
+}
+
+impl Segmentation {
+    pub fn nombre_clitiques(&self) -> usize {
+        self.proclitiques.len() + self.enclitiques.len()
+    }
+
+    // Forme lisible : "و+ال+مكتوب", "ب+كاتب+ه", "كاتب" sans clitique
+    pub fn etiquette(&self) -> String {
+        let mut parties: Vec<&str> = self.proclitiques.iter().map(|c| c.forme.as_str()).collect();
+        parties.push(&self.base);
+        parties.extend(self.enclitiques.iter().map(|c| c.forme.as_str()));
+        parties.join("+")
+    }
+
+    // La base peut-elle avoir ces propriétés, vu ses proclitiques ?
+    // ب، ك، ل et ال précèdent un nom, س un verbe à l'inaccompli ; sans nature
+    // connue (schème saisi sans propriétés), tout est admis
+    pub fn admet(&self, proprietes: &Proprietes) -> bool {
+        let Some(nature) = proprietes.nature else {
+            return true;
+        };
+        let verbe = nature == Nature::Verbe;
+        let inaccompli = proprietes
+            .aspect
+            .is_none_or(|aspect| aspect == Aspect::Inaccompli);
+        self.proclitiques.iter().all(|c| match c.role {
+            Role::Preposition | Role::Article => !verbe,
+            Role::Futur => verbe && inaccompli,
+            Role::Conjonction | Role::Pronom => true,
+        })
+    }
+}
+
+const CONJONCTIONS: [&str; 2] = ["و", "ف"];
+const PREPOSITIONS: [&str; 3] = ["ب", "ك", "ل"];
+const FUTUR: &str = "س";
+const ARTICLE: &str = "ال";
+
+// Pronoms suffixes
+const PRONOMS: [&str; 12] = [
+    "ه", "ها", "هما", "هم", "هن", "ك", "كما", "كم", "كن", "ي", "ني", "نا",
+];
+
+// Préfixes de l'inaccompli, seuls admis après س (أ est replié en ا)
+const PREFIXES_INACCOMPLI: [char; 4] = ['ي', 'ت', 'ن', 'ا'];
+
+// Une base a au moins deux lettres
+const BASE_MIN: usize = 2;
+
+// Comparaison des lettres : أ إ آ ٱ → ا, mais ة reste distinct de ه
+// (مكتبة n'est pas مكتب + ه)
+const LETTRES: Normalisation = Normalisation {
+    alifs: true,
+    ..Normalisation::SAISIE
+};
+
+// Toutes les découpes plausibles du mot, par nombre de clitiques croissant
+// La première est toujours le mot entier (sans clitique)
+pub fn segmenter(mot: &str) -> Vec<Segmentation> {
+    let saisie: String = Normalisation::SAISIE
+        .appliquer(mot)
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let unites = unites(&saisie);
+    let mut resultats: Vec<Segmentation> = Vec::new();
+
+    for (proclitiques, debut) in proclitiques_possibles(&unites) {
+        let article = proclitiques.iter().any(|c| c.role == Role::Article);
+        let futur = proclitiques.iter().any(|c| c.role == Role::Futur);
+
+        for (enclitique, fin) in enclitiques_possibles(&unites[debut..]) {
+            if article && enclitique.is_some() {
+                continue;
+            }
+            let base = &unites[debut..debut + fin];
+            if base.len() < BASE_MIN {
+                continue;
+            }
+            if futur && !PREFIXES_INACCOMPLI.contains(&lettre(&base[0])) {
+                continue;
+            }
+            for base in bases_possibles(base, article, enclitique.is_some()) {
+                let segmentation = Segmentation {
+                    proclitiques: proclitiques.clone(),
+                    base,
+                    enclitiques: enclitique.iter().cloned().collect(),
+                };
+                if !resultats.contains(&segmentation) {
+                    resultats.push(segmentation);
+                }
+            }
+        }
+    }
+
+    // Tri stable : à nombre égal, l'ordre d'énumération est gardé
+    resultats.sort_by_key(|s| s.nombre_clitiques());
+    resultats
+}
+
+// Découper le mot en lettres, chacune suivie de ses diacritiques
+fn unites(mot: &str) -> Vec<String> {
+    let mut unites: Vec<String> = Vec::new();
+    for c in mot.chars() {
+        match unites.last_mut() {
+            Some(unite) if c == SHADDA || est_diacritique(c) => unite.push(c),
+            _ => unites.push(c.to_string()),
+        }
+    }
+    unites
+}
+
+// Lettre de base d'une unité, repliée pour la comparaison
+fn lettre(unite: &str) -> char {
+    unite.chars().next().map_or(' ', |c| LETTRES.lettre(c))
+}
+
+// Les unités à partir de `debut` s'écrivent-elles `texte` ?
+fn commence_par(unites: &[String], debut: usize, texte: &str) -> bool {
+    texte
+        .chars()
+        .enumerate()
+        .all(|(i, c)| unites.get(debut + i).is_some_and(|u| lettre(u) == c))
+}
+
+// Suites de proclitiques possibles, avec l'indice de la première unité de la base
+fn proclitiques_possibles(unites: &[String]) -> Vec<(Vec<Clitique>, usize)> {
+    let clitique = |forme: &str, role| Clitique {
+        forme: forme.to_string(),
+        role,
+    };
+    let mut suites: Vec<(Vec<Clitique>, usize)> = vec![(Vec::new(), 0)];
+
+    // 1) Conjonction
+    for texte in CONJONCTIONS {
+        if commence_par(unites, 0, texte) {
+            suites.push((vec![clitique(texte, Role::Conjonction)], 1));
+        }
+    }
+
+    // 2) Préposition ou futur
+    let mut suivantes = Vec::new();
+    for (suite, debut) in &suites {
+        for (texte, role) in PREPOSITIONS
+            .iter()
+            .map(|p| (*p, Role::Preposition))
+            .chain([(FUTUR, Role::Futur)])
+        {
+            if commence_par(unites, *debut, texte) {
+                let mut suite = suite.clone();
+                suite.push(clitique(texte, role));
+                suivantes.push((suite, debut + 1));
+            }
+        }
+    }
+    suites.extend(suivantes);
+
+    // 3) Article (pas après س ; après ل il perd son alif : لل)
+    let mut suivantes = Vec::new();
+    for (suite, debut) in &suites {
+        let derniere = suite.last();
+        if derniere.is_some_and(|c| c.role == Role::Futur) {
+            continue;
+        }
+        let apres_lam = derniere.is_some_and(|c| c.role == Role::Preposition && c.forme == "ل");
+        let ecrit = if apres_lam { "ل" } else { ARTICLE };
+        if commence_par(unites, *debut, ecrit) {
+            let mut suite = suite.clone();
+            suite.push(clitique(ARTICLE, Role::Article));
+            suivantes.push((suite, debut + ecrit.chars().count()));
+        }
+    }
+    suites.extend(suivantes);
+    suites
+}
+
+// Pronom suffixe possible à la fin des unités, avec la longueur restante
+fn enclitiques_possibles(unites: &[String]) -> Vec<(Option<Clitique>, usize)> {
+    let mut resultats = vec![(None, unites.len())];
+    for pronom in PRONOMS {
+        let n = pronom.chars().count();
+        if n < unites.len() && commence_par(unites, unites.len() - n, pronom) {
+            let clitique = Clitique {
+                forme: pronom.to_string(),
+                role: Role::Pronom,
+            };
+            resultats.push((Some(clitique), unites.len() - n));
+        }
+    }
+    resultats
+}
+
+// Écritures possibles de la base une fois les clitiques retirés
+fn bases_possibles(unites: &[String], article: bool, pronom: bool) -> Vec<String> {
+    let mut unites = unites.to_vec();
+
+    // Lettre solaire : الشَّمْس → شَمْس
+    if article {
+        unites[0].retain(|c| c != SHADDA);
+    }
+
+    // Devant un pronom, la voyelle finale est une désinence : كِتَابُهُ → كِتَاب
+    let derniere = unites.len() - 1;
+    if pronom {
+        unites[derniere].retain(|c| !est_diacritique(c));
+    }
+
+    let base: String = unites.concat();
+    let mut bases = vec![base.clone()];
+    if pronom {
+        let debut: String = unites[..derniere].concat();
+        match lettre(&unites[derniere]) {
+            'ت' => bases.push(format!("{}ة", debut)),
+            'ا' => bases.push(format!("{}ى", debut)),
+            'و' => bases.push(format!("{}ا", base)),
+            _ => {}
+        }
+    }
+    bases
+}
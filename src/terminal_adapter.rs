@@ -7,6 +7,8 @@
 // À SUPPRIMER lors de la migration vers web/mobile !
 // ============================================================================
 
+use moteur_morphologique::normalisation::Normalisation;
+use moteur_morphologique::racine::Racine;
 use std::io;
 
 // Lire une ligne de texte depuis le terminal
@@ -18,24 +20,25 @@ pub fn lire_ligne_simple() -> String {
 
 // Lire du texte arabe depuis le terminal (sans inversion)
 // Le terminal Windows affiche en LTR mais on stocke en RTL
+// Tatweel et formes de présentation sont nettoyés (voir normalisation.rs)
 pub fn lire_texte_arabe() -> String {
-    let ligne = lire_ligne_simple();
-    // Enlever les espaces seulement
+    let ligne = Normalisation::SAISIE.appliquer(&lire_ligne_simple());
+    // Enlever les espaces
     ligne.chars().filter(|c| !c.is_whitespace()).collect()
 }
 
-// Lire une racine de 3 caractères arabes depuis le terminal
-pub fn lire_racine_terminal() -> Option<[char; 3]> {
-    println!("Entrez la racine (3 lettres séparées par des espaces, ex: ك ت ب) :");
+// Lire une racine de 3 ou 4 caractères arabes depuis le terminal
+pub fn lire_racine_terminal() -> Option<Racine> {
+    println!("Entrez la racine (3 ou 4 lettres séparées par des espaces, ex: ك ت ب ou ت ر ج م) :");
     let ligne = lire_ligne_simple();
-    let chars: Vec<char> = ligne.chars().filter(|c| !c.is_whitespace()).collect();
 
-    if chars.len() == 3 {
-        // Ne PAS inverser - stocker tel quel en RTL
-        Some([chars[0], chars[1], chars[2]])
-    } else {
-        println!("Erreur : vous devez entrer exactement 3 lettres.");
-        None
+    // Ne PAS inverser - stocker tel quel en RTL
+    match Racine::depuis_texte(&ligne) {
+        Ok(racine) => Some(racine),
+        Err(e) => {
+            println!("Erreur : {}.", e);
+            None
+        }
     }
 }
 
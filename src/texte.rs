@@ -0,0 +1,330 @@
+// ============================================================================
+// ANALYSE D'UN TEXTE COMPLET
+// ============================================================================
+// Un paragraphe collé par l'utilisateur est découpé en jetons (suites de
+// lettres arabes et de diacritiques ; ponctuation, chiffres et lettres latines
+// séparent les jetons), puis chaque jeton est analysé comme un mot de texte :
+// clitiques, désinences, racine et schème (voir analyser_mot_segmente).
+//
+// Statut d'un jeton :
+//   reconnu      → au moins une analyse dont la racine est dans l'arbre ;
+//                  seules ces analyses sont gardées comme candidats
+//   hors_lexique → des analyses, mais aucune racine de l'arbre
+//   inconnu      → aucune analyse (particule, nom propre, mot mal écrit...)
+//
+// Les statistiques portent sur tout le texte : couverture (part des jetons
+// reconnus) et racines / schèmes les plus fréquents parmi les jetons reconnus.
+// Un jeton répété n'est analysé qu'une fois.
+//
+// `compter_occurrences` reporte ensuite les jetons reconnus dans l'arbre :
+// fréquence des racines et occurrences des dérivés (lexique v5) ; un dérivé
+// reconnu qui n'était pas encore stocké sous sa racine y est ajouté.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arbre::{Derive, Tree};
+use crate::conjugaison::Conjugaison;
+use crate::flexion::Flexion;
+use crate::hashing::SchemeTable;
+use crate::morpho_analyzer::{analyser_mot_segmente, generer_mot};
+use crate::normalisation::Normalisation;
+use crate::racine::Racine;
+use crate::segmentation::Segmentation;
+
+// Nombre de racines et de schèmes les plus fréquents retenus par défaut
+pub const TOP_FREQUENCES: usize = 10;
+
+// Forme normalisée d'un jeton : sans voyelles ni shadda, alifs, hamzas, ى et ة repliés
+const FORME: Normalisation = Normalisation {
+    voyelles: true,
+    shadda: true,
+    ..Normalisation::COMPARAISON
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Statut {
+    Reconnu,
+    HorsLexique,
+    Inconnu,
+}
+
+impl Statut {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Statut::Reconnu => "reconnu",
+            Statut::HorsLexique => "hors_lexique",
+            Statut::Inconnu => "inconnu",
+        }
+    }
+}
+
+// Une analyse candidate d'un jeton
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Candidat {
+    pub segmentation: Segmentation,
+    pub racine: String,
+    pub scheme: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flexion: Option<Flexion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conjugaison: Option<Conjugaison>,
+    pub dans_arbre: bool,
+}
+
+// Un jeton du texte et ses analyses
+// `position` : indice (en caractères) du début du jeton dans le texte
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Jeton {
+    pub position: usize,
+    pub texte: String,
+    pub forme: String,
+    pub statut: Statut,
+    pub candidats: Vec<Candidat>,
+}
+
+// Une racine ou un schème et son nombre de jetons
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Frequence {
+    pub valeur: String,
+    pub occurrences: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Statistiques {
+    pub jetons: usize,
+    pub reconnus: usize,
+    pub hors_lexique: usize,
+    pub inconnus: usize,
+    // Pourcentage de jetons reconnus, arrondi au dixième
+    pub couverture: f64,
+    pub racines: Vec<Frequence>,
+    pub schemes: Vec<Frequence>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AnalyseTexte {
+    pub jetons: Vec<Jeton>,
+    pub statistiques: Statistiques,
+}
+
+// Analyser tout le texte ; `top` : nombre de racines et de schèmes fréquents
+pub fn analyser_texte(texte: &str, table: &SchemeTable, arbre: &Tree, top: usize) -> AnalyseTexte {
+    let mut cache: HashMap<String, (Statut, Vec<Candidat>)> = HashMap::new();
+    let mut jetons: Vec<Jeton> = Vec::new();
+
+    for (position, mot) in decouper(texte) {
+        let saisie = Normalisation::SAISIE.appliquer(&mot);
+        let (statut, candidats) = cache
+            .entry(saisie.clone())
+            .or_insert_with(|| analyser_jeton(&saisie, table, arbre))
+            .clone();
+        jetons.push(Jeton {
+            position,
+            forme: FORME.appliquer(&saisie),
+            texte: mot,
+            statut,
+            candidats,
+        });
+    }
+
+    let statistiques = statistiques(&jetons, top);
+    AnalyseTexte {
+        jetons,
+        statistiques,
+    }
+}
+
+// Découper le texte en jetons arabes, avec leur position
+pub fn decouper(texte: &str) -> Vec<(usize, String)> {
+    let mut jetons: Vec<(usize, String)> = Vec::new();
+    let mut courant: Option<(usize, String)> = None;
+
+    for (i, c) in texte.chars().enumerate() {
+        if est_caractere_arabe(c) {
+            courant.get_or_insert_with(|| (i, String::new())).1.push(c);
+        } else if let Some(jeton) = courant.take() {
+            jetons.push(jeton);
+        }
+    }
+    jetons.extend(courant);
+    jetons
+}
+
+// Lettres arabes (formes de présentation comprises), diacritiques et tatweel ;
+// la ponctuation arabe (، ؛ ؟) et les chiffres arabo-indiens n'en font pas partie
+fn est_caractere_arabe(c: char) -> bool {
+    matches!(c,
+        '\u{0621}'..='\u{0655}'
+        | '\u{0670}'..='\u{06D3}'
+        | '\u{FB50}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFC}')
+}
+
+// Analyses d'un jeton et son statut
+fn analyser_jeton(mot: &str, table: &SchemeTable, arbre: &Tree) -> (Statut, Vec<Candidat>) {
+    let analyses = analyser_mot_segmente(mot, table, arbre);
+    let statut = if analyses.is_empty() {
+        Statut::Inconnu
+    } else if analyses.iter().any(|a| a.hypothese.dans_arbre) {
+        Statut::Reconnu
+    } else {
+        Statut::HorsLexique
+    };
+
+    let candidats = analyses
+        .into_iter()
+        .filter(|a| statut != Statut::Reconnu || a.hypothese.dans_arbre)
+        .map(|a| Candidat {
+            segmentation: a.segmentation,
+            racine: a.hypothese.racine.to_string(),
+            scheme: a.hypothese.scheme,
+            flexion: a.hypothese.flexion,
+            conjugaison: a.hypothese.conjugaison,
+            dans_arbre: a.hypothese.dans_arbre,
+        })
+        .collect();
+    (statut, candidats)
+}
+
+fn statistiques(jetons: &[Jeton], top: usize) -> Statistiques {
+    let compter = |statut: Statut| jetons.iter().filter(|j| j.statut == statut).count();
+    let reconnus = compter(Statut::Reconnu);
+    let couverture = if jetons.is_empty() {
+        0.0
+    } else {
+        (reconnus as f64 * 1000.0 / jetons.len() as f64).round() / 10.0
+    };
+
+    // Un jeton ambigu compte pour chacune de ses racines (et chacun de ses
+    // schèmes), mais une seule fois pour chacune
+    let mut racines: HashMap<&str, u32> = HashMap::new();
+    let mut schemes: HashMap<&str, u32> = HashMap::new();
+    for jeton in jetons.iter().filter(|j| j.statut == Statut::Reconnu) {
+        let mut vues_racines: Vec<&str> = Vec::new();
+        let mut vues_schemes: Vec<&str> = Vec::new();
+        for c in &jeton.candidats {
+            if !vues_racines.contains(&c.racine.as_str()) {
+                vues_racines.push(&c.racine);
+                *racines.entry(&c.racine).or_default() += 1;
+            }
+            if !vues_schemes.contains(&c.scheme.as_str()) {
+                vues_schemes.push(&c.scheme);
+                *schemes.entry(&c.scheme).or_default() += 1;
+            }
+        }
+    }
+
+    Statistiques {
+        jetons: jetons.len(),
+        reconnus,
+        hors_lexique: compter(Statut::HorsLexique),
+        inconnus: compter(Statut::Inconnu),
+        couverture,
+        racines: plus_frequents(racines, top),
+        schemes: plus_frequents(schemes, top),
+    }
+}
+
+// Compter les jetons reconnus dans l'arbre : un jeton ambigu compte une fois
+// pour chacune de ses racines, et une fois pour chaque dérivé (même schème,
+// mêmes traits fléchis) de ses analyses ; un verbe conjugué compte pour la
+// forme de base de son schème (يكتبون → كَتَبَ)
+// Un dérivé absent de sa racine y est stocké avec une occurrence, comme par
+// valider_et_stocker : la base du jeton, ou la forme de base d'un verbe conjugué
+// Retourne le nombre de jetons comptés
+pub fn compter_occurrences(analyse: &AnalyseTexte, table: &SchemeTable, arbre: &mut Tree) -> usize {
+    let mut comptes = 0;
+    for jeton in analyse
+        .jetons
+        .iter()
+        .filter(|j| j.statut == Statut::Reconnu)
+    {
+        let mut vus: Vec<(&str, &str, Option<Flexion>)> = Vec::new();
+        let mut compte = false;
+        for c in &jeton.candidats {
+            let Ok(racine) = Racine::depuis_texte(&c.racine) else {
+                continue;
+            };
+            let Some(noeud) = arbre.chercher_noeud(racine) else {
+                continue;
+            };
+            if !vus.iter().any(|(r, _, _)| *r == c.racine) {
+                noeud.attester();
+                compte = true;
+            }
+            if !vus.contains(&(&c.racine, &c.scheme, c.flexion)) {
+                vus.push((&c.racine, &c.scheme, c.flexion));
+                if !noeud.attester_derive(&c.scheme, c.flexion) {
+                    let mot = match (&c.conjugaison, table.get(&c.scheme)) {
+                        (Some(_), Some(scheme)) => generer_mot(noeud.racine, scheme),
+                        _ => c.segmentation.base.clone(),
+                    };
+                    noeud.ajouter(Derive {
+                        mot,
+                        schema: c.scheme.clone(),
+                        flexion: c.flexion,
+                        occurrences: 1,
+                    });
+                }
+            }
+        }
+        if compte {
+            comptes += 1;
+        }
+    }
+    comptes
+}
+
+// Les `top` valeurs les plus fréquentes ; à égalité, ordre alphabétique
+fn plus_frequents(compteurs: HashMap<&str, u32>, top: usize) -> Vec<Frequence> {
+    let mut frequences: Vec<Frequence> = compteurs
+        .into_iter()
+        .map(|(valeur, occurrences)| Frequence {
+            valeur: valeur.to_string(),
+            occurrences,
+        })
+        .collect();
+    frequences.sort_by(|a, b| {
+        b.occurrences
+            .cmp(&a.occurrences)
+            .then_with(|| a.valeur.cmp(&b.valeur))
+    });
+    frequences.truncate(top);
+    frequences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashing::init_schemes;
+
+    #[test]
+    fn derives_reconnus_comptes_meme_sans_etre_stockes() {
+        let table = init_schemes();
+        let mut arbre = Tree::new();
+        arbre.charger_depuis_texte("ك ت ب").unwrap();
+
+        let analyse = analyser_texte("الكاتب كاتب ومكتوب", &table, &arbre, TOP_FREQUENCES);
+        assert_eq!(compter_occurrences(&analyse, &table, &mut arbre), 3);
+
+        let derives = arbre.derives_frequents(10);
+        let occurrences = |schema: &str| {
+            derives
+                .iter()
+                .find(|(_, d)| d.schema == schema)
+                .map(|(_, d)| d.occurrences)
+        };
+        assert_eq!(occurrences("فاعل"), Some(2));
+        assert_eq!(occurrences("مفعول"), Some(1));
+
+        // Une deuxième analyse compte sur les dérivés désormais stockés
+        let analyse = analyser_texte("كاتب", &table, &arbre, TOP_FREQUENCES);
+        compter_occurrences(&analyse, &table, &mut arbre);
+        let derives = arbre.derives_frequents(1);
+        assert_eq!(derives[0].1.schema, "فاعل");
+        assert_eq!(derives[0].1.occurrences, 3);
+    }
+}